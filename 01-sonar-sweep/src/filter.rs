@@ -0,0 +1,218 @@
+// Smoothing and anomaly detection over depth readings. Filters take one depth at a time, so
+// they can sit on a stream as easily as on a Vec.
+use aoc_core::ParseError;
+use std::{collections::VecDeque, fmt};
+
+pub trait Filter {
+    // The filtered value for the newest depth, None while the filter is still filling up
+    fn push(&mut self, depth: f64) -> Option<f64>;
+}
+
+// Mean of the last `window` depths
+pub struct MovingAverage {
+    window: usize,
+    recent: VecDeque<f64>,
+    sum: f64,
+}
+
+impl MovingAverage {
+    pub fn new(window: usize) -> Self {
+        assert!(window > 0, "window size must be at least 1");
+        MovingAverage {
+            window,
+            recent: VecDeque::with_capacity(window + 1),
+            sum: 0.0,
+        }
+    }
+}
+
+impl Filter for MovingAverage {
+    fn push(&mut self, depth: f64) -> Option<f64> {
+        self.recent.push_back(depth);
+        self.sum += depth;
+        if self.recent.len() > self.window {
+            self.sum -= self.recent.pop_front().unwrap();
+        }
+        if self.recent.len() < self.window {
+            return None;
+        }
+        Some(self.sum / self.window as f64)
+    }
+}
+
+// Median of the last `window` depths, which ignores the odd bad reading rather than
+// averaging it in
+pub struct Median {
+    window: usize,
+    recent: VecDeque<f64>,
+}
+
+impl Median {
+    pub fn new(window: usize) -> Self {
+        assert!(window > 0, "window size must be at least 1");
+        Median {
+            window,
+            recent: VecDeque::with_capacity(window + 1),
+        }
+    }
+}
+
+impl Filter for Median {
+    fn push(&mut self, depth: f64) -> Option<f64> {
+        self.recent.push_back(depth);
+        if self.recent.len() > self.window {
+            self.recent.pop_front();
+        }
+        if self.recent.len() < self.window {
+            return None;
+        }
+        let mut sorted: Vec<f64> = self.recent.iter().copied().collect();
+        sorted.sort_by(|a, b| a.total_cmp(b));
+        let mid = sorted.len() / 2;
+        if sorted.len().is_multiple_of(2) {
+            Some((sorted[mid - 1] + sorted[mid]) / 2.0)
+        } else {
+            Some(sorted[mid])
+        }
+    }
+}
+
+// Each output moves alpha of the way from the last output to the new depth. Smaller alphas
+// smooth more.
+pub struct Exponential {
+    alpha: f64,
+    current: Option<f64>,
+}
+
+impl Exponential {
+    pub fn new(alpha: f64) -> Self {
+        assert!(alpha > 0.0 && alpha <= 1.0, "alpha must be in (0, 1]");
+        Exponential {
+            alpha,
+            current: None,
+        }
+    }
+}
+
+impl Filter for Exponential {
+    fn push(&mut self, depth: f64) -> Option<f64> {
+        let next = match self.current {
+            Some(current) => current + self.alpha * (depth - current),
+            None => depth,
+        };
+        self.current = Some(next);
+        Some(next)
+    }
+}
+
+// "avg:5", "median:3" or "ema:0.2"
+pub fn parse_filter(spec: &str) -> Result<Box<dyn Filter>, ParseError> {
+    let (name, arg) = spec
+        .split_once(':')
+        .ok_or_else(|| ParseError::missing(spec, "':' and a parameter, eg avg:5"))?;
+    let window = || match arg.parse::<usize>() {
+        Ok(window) if window > 0 => Ok(window),
+        _ => Err(ParseError::new(spec, arg, "a window size of at least 1")),
+    };
+    match name {
+        "avg" => Ok(Box::new(MovingAverage::new(window()?))),
+        "median" => Ok(Box::new(Median::new(window()?))),
+        "ema" => match arg.parse::<f64>() {
+            Ok(alpha) if alpha > 0.0 && alpha <= 1.0 => Ok(Box::new(Exponential::new(alpha))),
+            _ => Err(ParseError::new(spec, arg, "an alpha between 0 and 1")),
+        },
+        _ => Err(ParseError::new(spec, name, "avg, median or ema")),
+    }
+}
+
+// A reading that was a long way from the ones before it
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Anomaly {
+    // 1-based line the reading was on
+    pub line: usize,
+    pub depth: i32,
+    // Of the readings before it
+    pub mean: f64,
+    pub stddev: f64,
+}
+
+impl Anomaly {
+    // How many standard deviations from the mean the reading was
+    pub fn sigmas(&self) -> f64 {
+        (self.depth as f64 - self.mean).abs() / self.stddev
+    }
+}
+
+impl fmt::Display for Anomaly {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}: {} is {:.1} stddevs from the rolling mean of {:.1}",
+            self.line,
+            self.depth,
+            self.sigmas(),
+            self.mean
+        )
+    }
+}
+
+// Flags readings more than k standard deviations from the mean of the `window` readings
+// before them. Nothing is flagged until there's a full window to compare against.
+pub struct Outliers {
+    window: usize,
+    k: f64,
+    recent: VecDeque<f64>,
+}
+
+impl Outliers {
+    pub fn new(window: usize, k: f64) -> Self {
+        assert!(window > 0, "window size must be at least 1");
+        Outliers {
+            window,
+            k,
+            recent: VecDeque::with_capacity(window + 1),
+        }
+    }
+
+    pub fn push(&mut self, line: usize, depth: i32) -> Option<Anomaly> {
+        let mut anomaly = None;
+        if self.recent.len() == self.window {
+            let n = self.window as f64;
+            let mean = self.recent.iter().sum::<f64>() / n;
+            let variance = self
+                .recent
+                .iter()
+                .map(|x| (x - mean) * (x - mean))
+                .sum::<f64>()
+                / n;
+            let stddev = variance.sqrt();
+            if (depth as f64 - mean).abs() > self.k * stddev {
+                anomaly = Some(Anomaly {
+                    line,
+                    depth,
+                    mean,
+                    stddev,
+                });
+            }
+        }
+        // Anomalies stay in the window, so a lasting change in depth soon stops being flagged
+        self.recent.push_back(depth as f64);
+        if self.recent.len() > self.window {
+            self.recent.pop_front();
+        }
+        anomaly
+    }
+}
+
+// Every anomaly in a run of (line, depth) readings
+pub fn outliers(
+    readings: impl IntoIterator<Item = (usize, i32)>,
+    window: usize,
+    k: f64,
+) -> Vec<Anomaly> {
+    let mut detector = Outliers::new(window, k);
+    readings
+        .into_iter()
+        .filter_map(|(line, depth)| detector.push(line, depth))
+        .collect()
+}
@@ -0,0 +1,25 @@
+pub mod filter;
+mod pt1;
+mod pt2;
+pub mod stream;
+pub mod window;
+
+pub use filter::{Anomaly, Filter, Outliers};
+pub use pt1::Part1;
+pub use pt2::Part2;
+pub use stream::Stream;
+pub use window::{windowed_increases, Change, MonotonicRun, WindowStats, Windows};
+
+use aoc_core::Answer;
+
+// The puzzle only wants the increases, the rest is there for -f json
+fn answer(stats: &WindowStats) -> Answer {
+    let mut answer = Answer::new(stats.increases)
+        .with("windows", stats.windows)
+        .with("decreases", stats.decreases)
+        .with("plateaus", stats.plateaus);
+    if let Some(run) = stats.longest_run {
+        answer = answer.with("longest_run", run.to_string());
+    }
+    answer
+}
@@ -0,0 +1,26 @@
+use crate::{answer, Windows};
+use aoc_core::{debug, parse, trace, Answer, Result, Solver};
+
+pub struct Part1;
+
+impl Solver for Part1 {
+    type Input = Vec<i32>;
+
+    fn parse(&self, input: &str) -> Result<Vec<i32>> {
+        debug!("Reading input");
+        let depths = parse::lines(input.as_bytes())?;
+        debug!("Read input");
+        Ok(depths)
+    }
+
+    fn solve(&self, depths: &Vec<i32>) -> Result<Answer> {
+        // Each depth against the one before
+        let mut windows = Windows::new(1);
+        for &depth in depths {
+            if let Some(change) = windows.push(depth) {
+                trace!("{} {:?}", depth, change);
+            }
+        }
+        Ok(answer(windows.stats()))
+    }
+}
@@ -0,0 +1,21 @@
+use crate::{answer, windowed_increases};
+use aoc_core::{debug, parse, Answer, Result, Solver};
+
+pub struct Part2;
+
+impl Solver for Part2 {
+    type Input = Vec<i32>;
+
+    fn parse(&self, input: &str) -> Result<Vec<i32>> {
+        debug!("Reading input");
+        let depths: Vec<i32> = parse::lines(input.as_bytes())?;
+        debug!("Read input");
+        Ok(depths)
+    }
+
+    fn solve(&self, depths: &Vec<i32>) -> Result<Answer> {
+        // Sums of three depths in a row
+        let stats = windowed_increases(depths.iter(), 3);
+        Ok(answer(&stats))
+    }
+}
@@ -0,0 +1,93 @@
+// Counting increases as depths arrive, eg piped from a live sensor log, rather than after
+// reading the whole input. Only the last few depths for each window size are kept.
+use crate::Windows;
+use aoc_core::{parse, Result};
+use std::{fmt, io::BufRead};
+
+pub struct Stream {
+    windows: Vec<Windows<i32>>,
+    depths: usize,
+}
+
+impl Stream {
+    // Tracks every window size in sizes at once
+    pub fn new(sizes: &[usize]) -> Self {
+        Stream {
+            windows: sizes.iter().map(|&size| Windows::new(size)).collect(),
+            depths: 0,
+        }
+    }
+
+    pub fn push(&mut self, depth: i32) {
+        self.depths += 1;
+        for windows in &mut self.windows {
+            windows.push(depth);
+        }
+    }
+
+    // Depths seen so far
+    pub fn depths(&self) -> usize {
+        self.depths
+    }
+
+    pub fn windows(&self) -> &[Windows<i32>] {
+        &self.windows
+    }
+
+    // Read depths until the reader runs out, calling on_depth with the line and depth of each
+    // one as it's counted, and report after every `every` depths (never, if every is 0).
+    // Stops at the first line that isn't a depth.
+    pub fn read<R: BufRead>(
+        &mut self,
+        reader: R,
+        every: usize,
+        mut on_depth: impl FnMut(usize, i32),
+        mut report: impl FnMut(&Stream),
+    ) -> Result<()> {
+        for depth in depths(reader) {
+            let (line, depth) = depth?;
+            self.push(depth);
+            on_depth(line, depth);
+            if every > 0 && self.depths.is_multiple_of(every) {
+                report(self);
+            }
+        }
+        Ok(())
+    }
+}
+
+// One depth per line, with its 1-based line number. Blank lines are skipped, and lines are
+// only read as they're needed.
+pub fn depths<R: BufRead>(reader: R) -> impl Iterator<Item = Result<(usize, i32)>> {
+    reader
+        .lines()
+        .enumerate()
+        .filter_map(|(i, line)| match line {
+            Ok(line) if line.trim().is_empty() => None,
+            Ok(line) => Some(
+                parse::field(&line, line.trim())
+                    .map(|depth| (i + 1, depth))
+                    .map_err(|e| e.at_line(i + 1).into()),
+            ),
+            Err(e) => Some(Err(e.into())),
+        })
+}
+
+// 10 depths | window 1: 7 up, 2 down, 0 flat | window 3: 5 up, 1 down, 1 flat
+impl fmt::Display for Stream {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} depths", self.depths)?;
+        for windows in &self.windows {
+            let stats = windows.stats();
+            write!(
+                f,
+                " | window {}: {} up, {} down, {} flat",
+                windows.window(),
+                stats.increases,
+                stats.decreases,
+                stats.plateaus
+            )?;
+        }
+        Ok(())
+    }
+}
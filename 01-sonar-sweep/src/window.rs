@@ -0,0 +1,143 @@
+// Sliding window sums over a stream of depths, compared one window to the next.
+//
+// Neighbouring windows share all but one depth, so comparing their sums is the same as
+// comparing the depth entering the window with the one leaving it. That means only the
+// last `window` depths need to be kept, the sums are never computed (so can't overflow), and
+// the depths can come from any iterator, however long.
+use std::{cmp::Ordering, collections::VecDeque, fmt};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Change {
+    Increase,
+    Decrease,
+    Plateau,
+}
+
+// A stretch of windows that kept going the same way
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MonotonicRun {
+    // Increase or Decrease, plateaus end a run
+    pub change: Change,
+    // 0-based index of the first window in the run
+    pub start: usize,
+    // Number of changes in a row, so the run covers steps + 1 windows
+    pub steps: usize,
+}
+
+impl fmt::Display for MonotonicRun {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let direction = match self.change {
+            Change::Increase => "increases",
+            Change::Decrease => "decreases",
+            Change::Plateau => "plateaus",
+        };
+        write!(f, "{} {} from window {}", self.steps, direction, self.start)
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WindowStats {
+    // Number of complete windows seen
+    pub windows: usize,
+    pub increases: usize,
+    pub decreases: usize,
+    pub plateaus: usize,
+    // The first of the longest runs, None if there wasn't a single increase or decrease
+    pub longest_run: Option<MonotonicRun>,
+}
+
+// Running state for one window size. Depths are pushed in one at a time.
+pub struct Windows<T> {
+    window: usize,
+    // The depths in the current window, plus the one entering it
+    recent: VecDeque<T>,
+    stats: WindowStats,
+    current_run: Option<MonotonicRun>,
+}
+
+impl<T: Ord> Windows<T> {
+    pub fn new(window: usize) -> Self {
+        assert!(window > 0, "window size must be at least 1");
+        Windows {
+            window,
+            recent: VecDeque::with_capacity(window + 1),
+            stats: WindowStats::default(),
+            current_run: None,
+        }
+    }
+
+    // Add the next depth. Once there are two windows, returns how the newest compares to the
+    // one before.
+    pub fn push(&mut self, depth: T) -> Option<Change> {
+        self.recent.push_back(depth);
+        if self.recent.len() < self.window {
+            return None;
+        }
+        self.stats.windows += 1;
+        if self.recent.len() == self.window {
+            return None;
+        }
+
+        let leaving = self.recent.pop_front().unwrap();
+        let entering = self.recent.back().unwrap();
+        let change = match entering.cmp(&leaving) {
+            Ordering::Greater => Change::Increase,
+            Ordering::Less => Change::Decrease,
+            Ordering::Equal => Change::Plateau,
+        };
+        self.record(change);
+        Some(change)
+    }
+
+    fn record(&mut self, change: Change) {
+        match change {
+            Change::Increase => self.stats.increases += 1,
+            Change::Decrease => self.stats.decreases += 1,
+            Change::Plateau => self.stats.plateaus += 1,
+        }
+
+        // Index of the window before the one just completed
+        let previous = self.stats.windows - 2;
+        self.current_run = match (change, self.current_run) {
+            (Change::Plateau, _) => None,
+            (change, Some(run)) if run.change == change => Some(MonotonicRun {
+                steps: run.steps + 1,
+                ..run
+            }),
+            (change, _) => Some(MonotonicRun {
+                change,
+                start: previous,
+                steps: 1,
+            }),
+        };
+        if let Some(run) = self.current_run {
+            if self
+                .stats
+                .longest_run
+                .is_none_or(|longest| run.steps > longest.steps)
+            {
+                self.stats.longest_run = Some(run);
+            }
+        }
+    }
+
+    pub fn stats(&self) -> &WindowStats {
+        &self.stats
+    }
+
+    pub fn window(&self) -> usize {
+        self.window
+    }
+}
+
+// How the sums of each `window` consecutive depths change, in one pass
+pub fn windowed_increases<T: Ord>(
+    depths: impl IntoIterator<Item = T>,
+    window: usize,
+) -> WindowStats {
+    let mut windows = Windows::new(window);
+    for depth in depths {
+        windows.push(depth);
+    }
+    windows.stats
+}
@@ -0,0 +1,52 @@
+use sonar_sweep::filter::{self, Exponential, Filter, Median, MovingAverage};
+
+fn run(mut filter: impl Filter, depths: &[f64]) -> Vec<Option<f64>> {
+    depths.iter().map(|&depth| filter.push(depth)).collect()
+}
+
+#[test]
+fn moving_average_waits_for_a_full_window() {
+    let out = run(MovingAverage::new(3), &[3.0, 6.0, 9.0, 12.0]);
+    assert_eq!(out, [None, None, Some(6.0), Some(9.0)]);
+}
+
+#[test]
+fn median_ignores_a_spike() {
+    let out = run(Median::new(3), &[10.0, 11.0, 500.0, 12.0, 13.0]);
+    assert_eq!(out, [None, None, Some(11.0), Some(12.0), Some(13.0)]);
+    let out = run(Median::new(2), &[1.0, 4.0]);
+    assert_eq!(out, [None, Some(2.5)]);
+}
+
+#[test]
+fn exponential_starts_at_the_first_depth() {
+    let out = run(Exponential::new(0.5), &[10.0, 20.0, 20.0]);
+    assert_eq!(out, [Some(10.0), Some(15.0), Some(17.5)]);
+}
+
+#[test]
+fn filter_specs() {
+    let mut avg = filter::parse_filter("avg:2").unwrap();
+    assert_eq!(avg.push(1.0), None);
+    assert_eq!(avg.push(2.0), Some(1.5));
+
+    let e = filter::parse_filter("median:0").err().unwrap();
+    assert_eq!(e.column, 8);
+    let e = filter::parse_filter("mode:3").err().unwrap();
+    assert_eq!(e.token, "mode");
+    assert!(filter::parse_filter("ema").is_err());
+}
+
+#[test]
+fn outliers_are_flagged_with_their_lines() {
+    let readings = [100, 101, 99, 100, 101, 160, 100, 99]
+        .into_iter()
+        .enumerate()
+        .map(|(i, depth)| (i + 1, depth));
+    let anomalies = filter::outliers(readings, 4, 3.0);
+    assert_eq!(anomalies.len(), 1);
+    assert_eq!(anomalies[0].line, 6);
+    assert_eq!(anomalies[0].depth, 160);
+    assert_eq!(anomalies[0].mean, 100.25);
+    assert!(anomalies[0].sigmas() > 3.0);
+}
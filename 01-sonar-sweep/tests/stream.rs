@@ -0,0 +1,57 @@
+use aoc_core::Error;
+use sonar_sweep::Stream;
+
+#[test]
+fn reports_every_n_depths() {
+    let input = "199\n200\n208\n210\n\n200\n207\n240\n269\n260\n263\n";
+    let mut stream = Stream::new(&[1, 3]);
+    let mut reports = Vec::new();
+    stream
+        .read(
+            input.as_bytes(),
+            4,
+            |_, _| {},
+            |s| reports.push(s.to_string()),
+        )
+        .unwrap();
+    assert_eq!(
+        reports,
+        [
+            "4 depths | window 1: 3 up, 0 down, 0 flat | window 3: 1 up, 0 down, 0 flat",
+            "8 depths | window 1: 6 up, 1 down, 0 flat | window 3: 3 up, 1 down, 1 flat",
+        ]
+    );
+    assert_eq!(stream.windows()[0].stats().increases, 7);
+    assert_eq!(stream.windows()[1].stats().increases, 5);
+}
+
+#[test]
+fn empty_and_single_depth_inputs() {
+    let mut stream = Stream::new(&[1, 3]);
+    stream.read("".as_bytes(), 1, |_, _| {}, |_| {}).unwrap();
+    assert_eq!(stream.depths(), 0);
+
+    stream
+        .read("42\n".as_bytes(), 1, |_, _| {}, |_| {})
+        .unwrap();
+    assert_eq!(stream.depths(), 1);
+    assert_eq!(stream.windows()[0].stats().windows, 1);
+    assert_eq!(stream.windows()[1].stats().windows, 0);
+}
+
+#[test]
+fn bad_line_stops_with_its_line_number() {
+    let mut stream = Stream::new(&[1]);
+    let mut lines = Vec::new();
+    match stream.read(
+        "1\n2\nthree\n4\n".as_bytes(),
+        0,
+        |line, _| lines.push(line),
+        |_| {},
+    ) {
+        Err(Error::Parse(e)) => assert_eq!(e.line, Some(3)),
+        other => panic!("expected a parse error, got {:?}", other.err()),
+    }
+    assert_eq!(stream.depths(), 2);
+    assert_eq!(lines, [1, 2]);
+}
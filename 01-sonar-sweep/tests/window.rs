@@ -0,0 +1,53 @@
+use sonar_sweep::{windowed_increases, Change, MonotonicRun, Windows};
+
+const EXAMPLE: [i32; 10] = [199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+
+#[test]
+fn example_windows_of_one_and_three() {
+    let stats = windowed_increases(EXAMPLE, 1);
+    assert_eq!(stats.windows, 10);
+    assert_eq!(stats.increases, 7);
+    assert_eq!(stats.decreases, 2);
+
+    let stats = windowed_increases(EXAMPLE, 3);
+    assert_eq!(stats.windows, 8);
+    assert_eq!(stats.increases, 5);
+    assert_eq!(stats.plateaus, 1);
+}
+
+#[test]
+fn any_window_size_matches_summing() {
+    for window in 1..=EXAMPLE.len() + 1 {
+        let sums: Vec<i32> = EXAMPLE.windows(window).map(|w| w.iter().sum()).collect();
+        let expected = sums.windows(2).filter(|pair| pair[1] > pair[0]).count();
+        let stats = windowed_increases(EXAMPLE, window);
+        assert_eq!(stats.windows, sums.len());
+        assert_eq!(stats.increases, expected, "window {}", window);
+    }
+}
+
+#[test]
+fn longest_run_ends_at_plateaus() {
+    let stats = windowed_increases([5, 4, 3, 3, 4, 5, 6, 6, 1], 1);
+    assert_eq!(
+        stats.longest_run,
+        Some(MonotonicRun {
+            change: Change::Increase,
+            start: 3,
+            steps: 3
+        })
+    );
+}
+
+#[test]
+fn short_input_has_no_changes() {
+    let stats = windowed_increases(Vec::<i32>::new(), 3);
+    assert_eq!(stats.windows, 0);
+    assert_eq!(stats.longest_run, None);
+
+    let mut windows = Windows::new(2);
+    assert_eq!(windows.push(1), None);
+    assert_eq!(windows.push(2), None);
+    assert_eq!(windows.push(3), Some(Change::Increase));
+    assert_eq!(windows.stats().windows, 2);
+}
@@ -0,0 +1,13 @@
+pub mod lint;
+pub mod number;
+mod pt1;
+mod pt2;
+pub mod submarine;
+pub mod trajectory;
+
+pub use lint::{lint, Finding, Severity};
+pub use number::Number;
+pub use pt1::Part1;
+pub use pt2::Part2;
+pub use submarine::{Aim, Command, CommandSemantics, Instruction, Naive, State, Submarine};
+pub use trajectory::{Step, Trajectory};
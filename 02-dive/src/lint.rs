@@ -0,0 +1,105 @@
+// Checks a whole course file, rather than stopping at the first bad line like the solvers do.
+// Lines that don't parse are errors. Lines that parse but probably aren't what was meant are
+// warnings, eg "forward 0", or a course that takes the submarine above the surface.
+use crate::{Number, Submarine};
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Finding {
+    // 1-based
+    pub line: usize,
+    pub severity: Severity,
+    // Starts with the line number, parse errors include the caret diagnostic
+    pub message: String,
+}
+
+impl fmt::Display for Finding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let severity = match self.severity {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        };
+        write!(f, "{}: {}", severity, self.message)
+    }
+}
+
+fn warning(line: usize, message: impl fmt::Display) -> Finding {
+    Finding {
+        line,
+        severity: Severity::Warning,
+        message: format!("line {}: {}", line, message),
+    }
+}
+
+// Everything wrong with input, in line order. The course is run on submarine as it goes, so
+// its semantics decide what counts as going negative.
+pub fn lint<N: Number>(mut submarine: Submarine<N>, input: &str) -> Vec<Finding> {
+    let zero = N::default();
+    let mut findings = Vec::new();
+    for (i, line) in input.lines().enumerate() {
+        let n = i + 1;
+        if line.trim().is_empty() {
+            continue;
+        }
+        if line != line.trim_end() {
+            findings.push(warning(n, "trailing whitespace"));
+        }
+
+        let command = match submarine.parse(line) {
+            Ok(command) => command,
+            Err(e) => {
+                findings.push(Finding {
+                    line: n,
+                    severity: Severity::Error,
+                    message: e.at_line(n).to_string(),
+                });
+                continue;
+            }
+        };
+        if submarine.takes_amount(&command) {
+            let amount = command.amount();
+            if *amount == zero {
+                findings.push(warning(n, "amount is 0, so this does nothing"));
+            } else if *amount < zero {
+                findings.push(warning(
+                    n,
+                    format!("negative amount {}, which reverses the command", amount),
+                ));
+            }
+        }
+
+        let before = submarine.state().clone();
+        if let Err(e) = submarine.run(n, &command) {
+            findings.push(Finding {
+                line: n,
+                severity: Severity::Error,
+                message: e.to_string(),
+            });
+            continue;
+        }
+        // Only where it first happens, not for every line after
+        let after = submarine.state();
+        if after.aim < zero && before.aim >= zero {
+            findings.push(warning(
+                n,
+                format!(
+                    "aim goes negative ({}), pointing the submarine up",
+                    after.aim
+                ),
+            ));
+        }
+        if after.depth < zero && before.depth >= zero {
+            findings.push(warning(
+                n,
+                format!("depth goes negative ({}), above the surface", after.depth),
+            ));
+        }
+    }
+    findings
+}
@@ -0,0 +1,63 @@
+// The numbers the submarine keeps its position in. Everything is checked, so a course that
+// goes deeper than the type can hold is an error rather than a wrapped answer.
+use num_bigint::BigInt;
+use num_traits::ToPrimitive;
+use std::{
+    fmt::{Debug, Display},
+    str::FromStr,
+};
+
+pub trait Number: Clone + Default + PartialOrd + Display + Debug + FromStr + 'static {
+    fn checked_add(&self, rhs: &Self) -> Option<Self>;
+    fn checked_sub(&self, rhs: &Self) -> Option<Self>;
+    fn checked_mul(&self, rhs: &Self) -> Option<Self>;
+    fn checked_neg(&self) -> Option<Self>;
+    // For drawing, where being a little off doesn't matter
+    fn to_f64(&self) -> f64;
+}
+
+macro_rules! primitive_number {
+    ($($t:ty),*) => {
+        $(
+            impl Number for $t {
+                fn checked_add(&self, rhs: &Self) -> Option<Self> {
+                    <$t>::checked_add(*self, *rhs)
+                }
+                fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+                    <$t>::checked_sub(*self, *rhs)
+                }
+                fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+                    <$t>::checked_mul(*self, *rhs)
+                }
+                fn checked_neg(&self) -> Option<Self> {
+                    <$t>::checked_neg(*self)
+                }
+                fn to_f64(&self) -> f64 {
+                    *self as f64
+                }
+            }
+        )*
+    };
+}
+
+primitive_number!(i32, i64, i128);
+
+// Never overflows
+impl Number for BigInt {
+    fn checked_add(&self, rhs: &Self) -> Option<Self> {
+        Some(self + rhs)
+    }
+    fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        Some(self - rhs)
+    }
+    fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+        Some(self * rhs)
+    }
+    fn checked_neg(&self) -> Option<Self> {
+        Some(-self)
+    }
+    fn to_f64(&self) -> f64 {
+        // Saturates to infinity rather than failing, so this is always Some
+        ToPrimitive::to_f64(self).expect("BigInt converts to f64")
+    }
+}
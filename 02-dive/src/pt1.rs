@@ -0,0 +1,26 @@
+use crate::{Command, Naive, State, Submarine, Trajectory};
+use aoc_core::{debug, parse, Answer, Result, Solver};
+
+pub struct Part1;
+
+impl Solver for Part1 {
+    // Commands and the lines they're on. i64 so long courses don't overflow.
+    type Input = Vec<(usize, Command<i64>)>;
+
+    fn parse(&self, input: &str) -> Result<Vec<(usize, Command<i64>)>> {
+        debug!("Reading input");
+        let submarine = Submarine::<i64>::new(Naive);
+        parse::numbered_lines(input.as_bytes(), |line| submarine.parse(line))
+    }
+
+    fn solve(&self, commands: &Vec<(usize, Command<i64>)>) -> Result<Answer> {
+        let mut submarine = Submarine::new(Naive);
+        let trajectory = Trajectory::record(&mut submarine, commands)?;
+
+        let State { pos, depth, .. } = submarine.state();
+        Ok(Answer::new(submarine.product()?)
+            .with("pos", pos)
+            .with("depth", depth)
+            .with("max_depth", trajectory.max_depth().state.depth))
+    }
+}
@@ -0,0 +1,27 @@
+use crate::{Aim, Command, State, Submarine, Trajectory};
+use aoc_core::{debug, parse, Answer, Result, Solver};
+
+pub struct Part2;
+
+impl Solver for Part2 {
+    // Commands and the lines they're on. i64 so long courses don't overflow.
+    type Input = Vec<(usize, Command<i64>)>;
+
+    fn parse(&self, input: &str) -> Result<Vec<(usize, Command<i64>)>> {
+        debug!("Reading input");
+        let submarine = Submarine::<i64>::new(Aim);
+        parse::numbered_lines(input.as_bytes(), |line| submarine.parse(line))
+    }
+
+    fn solve(&self, commands: &Vec<(usize, Command<i64>)>) -> Result<Answer> {
+        let mut submarine = Submarine::new(Aim);
+        let trajectory = Trajectory::record(&mut submarine, commands)?;
+
+        let State { pos, depth, aim } = submarine.state();
+        Ok(Answer::new(submarine.product()?)
+            .with("pos", pos)
+            .with("depth", depth)
+            .with("max_depth", trajectory.max_depth().state.depth)
+            .with("aim", aim))
+    }
+}
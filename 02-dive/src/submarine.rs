@@ -0,0 +1,253 @@
+// The submarine and the commands it understands. Both parts read the same course file, they
+// just disagree about what down and up mean, so that's the only thing that's pluggable:
+// CommandSemantics decides what forward / down / up do to the state. Extra commands can be
+// registered on top, eg for variants of the puzzle.
+//
+// Every operation returns None if it overflows N.
+use crate::Number;
+use aoc_core::{parse, Error, ParseError, Result};
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct State<N = i32> {
+    pub pos: N,
+    pub depth: N,
+    pub aim: N,
+}
+
+// What the three built in commands do
+pub trait CommandSemantics<N: Number> {
+    fn forward(&self, state: &mut State<N>, amount: &N) -> Option<()>;
+    fn down(&self, state: &mut State<N>, amount: &N) -> Option<()>;
+    fn up(&self, state: &mut State<N>, amount: &N) -> Option<()>;
+}
+
+// Part 1: down and up change depth directly
+pub struct Naive;
+
+impl<N: Number> CommandSemantics<N> for Naive {
+    fn forward(&self, state: &mut State<N>, amount: &N) -> Option<()> {
+        state.pos = state.pos.checked_add(amount)?;
+        Some(())
+    }
+    fn down(&self, state: &mut State<N>, amount: &N) -> Option<()> {
+        state.depth = state.depth.checked_add(amount)?;
+        Some(())
+    }
+    fn up(&self, state: &mut State<N>, amount: &N) -> Option<()> {
+        state.depth = state.depth.checked_sub(amount)?;
+        Some(())
+    }
+}
+
+// Part 2: down and up change aim, and forward moves along it
+pub struct Aim;
+
+impl<N: Number> CommandSemantics<N> for Aim {
+    fn forward(&self, state: &mut State<N>, amount: &N) -> Option<()> {
+        state.pos = state.pos.checked_add(amount)?;
+        state.depth = state.depth.checked_add(&state.aim.checked_mul(amount)?)?;
+        Some(())
+    }
+    fn down(&self, state: &mut State<N>, amount: &N) -> Option<()> {
+        state.aim = state.aim.checked_add(amount)?;
+        Some(())
+    }
+    fn up(&self, state: &mut State<N>, amount: &N) -> Option<()> {
+        state.aim = state.aim.checked_sub(amount)?;
+        Some(())
+    }
+}
+
+// A command beyond forward / down / up
+pub trait Instruction<N: Number> {
+    // What it's called in the course file
+    fn verb(&self) -> &str;
+    // Whether it's followed by an amount, eg "back 3" but just "surface"
+    fn takes_amount(&self) -> bool {
+        true
+    }
+    // amount is 0 for commands that don't take one
+    fn apply(&self, state: &mut State<N>, amount: &N) -> Option<()>;
+}
+
+// "back 3": reverse along the course
+pub struct Back;
+
+impl<N: Number> Instruction<N> for Back {
+    fn verb(&self) -> &str {
+        "back"
+    }
+    fn apply(&self, state: &mut State<N>, amount: &N) -> Option<()> {
+        state.pos = state.pos.checked_sub(amount)?;
+        Some(())
+    }
+}
+
+// "surface": straight back up, level
+pub struct Surface;
+
+impl<N: Number> Instruction<N> for Surface {
+    fn verb(&self) -> &str {
+        "surface"
+    }
+    fn takes_amount(&self) -> bool {
+        false
+    }
+    fn apply(&self, state: &mut State<N>, _: &N) -> Option<()> {
+        state.depth = N::default();
+        state.aim = N::default();
+        Some(())
+    }
+}
+
+// "turn": flip the aim, so a dive becomes a climb
+pub struct Turn;
+
+impl<N: Number> Instruction<N> for Turn {
+    fn verb(&self) -> &str {
+        "turn"
+    }
+    fn takes_amount(&self) -> bool {
+        false
+    }
+    fn apply(&self, state: &mut State<N>, _: &N) -> Option<()> {
+        state.aim = state.aim.checked_neg()?;
+        Some(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command<N = i32> {
+    Forward(N),
+    Down(N),
+    Up(N),
+    // Index into the submarine's registered instructions, and the amount (0 if none)
+    Extra(usize, N),
+}
+
+impl<N> Command<N> {
+    pub fn amount(&self) -> &N {
+        match self {
+            Command::Forward(amount)
+            | Command::Down(amount)
+            | Command::Up(amount)
+            | Command::Extra(_, amount) => amount,
+        }
+    }
+}
+
+pub struct Submarine<N: Number = i32> {
+    semantics: Box<dyn CommandSemantics<N>>,
+    extras: Vec<Box<dyn Instruction<N>>>,
+    state: State<N>,
+}
+
+impl<N: Number> Submarine<N> {
+    pub fn new(semantics: impl CommandSemantics<N> + 'static) -> Self {
+        Submarine {
+            semantics: Box::new(semantics),
+            extras: Vec::new(),
+            state: State::default(),
+        }
+    }
+
+    // Later registrations win if two instructions use the same verb
+    pub fn register(mut self, instruction: impl Instruction<N> + 'static) -> Self {
+        self.extras.push(Box::new(instruction));
+        self
+    }
+
+    fn verbs(&self) -> String {
+        let mut verbs = vec!["forward", "down", "up"];
+        verbs.extend(self.extras.iter().map(|e| e.verb()));
+        let last = verbs.pop().unwrap();
+        format!("{} or {}", verbs.join(", "), last)
+    }
+
+    // "forward 3", or any registered command. Trailing whitespace is ignored (lint warns
+    // about it).
+    pub fn parse(&self, s: &str) -> Result<Command<N>, ParseError> {
+        let s = s.trim_end();
+        let mut components = s.split(' ');
+        // ie "forward"
+        let action = components.next().unwrap_or(s);
+        let extra = self.extras.iter().rposition(|e| e.verb() == action);
+        let takes_amount = extra.is_none_or(|i| self.extras[i].takes_amount());
+
+        // ie "3"
+        let amount: N = match (components.next(), takes_amount) {
+            (Some(nstr), true) => parse::field(s, nstr)?,
+            (None, true) => return Err(ParseError::missing(s, "an amount")),
+            (Some(extra), false) => return Err(ParseError::new(s, extra, "end of line")),
+            (None, false) => N::default(),
+        };
+        if let Some(extra) = components.next() {
+            return Err(ParseError::new(s, extra, "end of line"));
+        }
+
+        return match (action, extra) {
+            (_, Some(i)) => Ok(Command::Extra(i, amount)),
+            ("forward", _) => Ok(Command::Forward(amount)),
+            ("down", _) => Ok(Command::Down(amount)),
+            ("up", _) => Ok(Command::Up(amount)),
+            _ => Err(ParseError::new(s, action, self.verbs())),
+        };
+    }
+
+    // Whether command was followed by an amount in the course file
+    pub fn takes_amount(&self, command: &Command<N>) -> bool {
+        match command {
+            Command::Extra(i, _) => self.extras[*i].takes_amount(),
+            _ => true,
+        }
+    }
+
+    // None if the command overflowed, in which case the state is left as it was
+    pub fn execute(&mut self, command: &Command<N>) -> Option<()> {
+        let mut state = self.state.clone();
+        match command {
+            Command::Forward(amount) => self.semantics.forward(&mut state, amount)?,
+            Command::Down(amount) => self.semantics.down(&mut state, amount)?,
+            Command::Up(amount) => self.semantics.up(&mut state, amount)?,
+            Command::Extra(i, amount) => self.extras[*i].apply(&mut state, amount)?,
+        }
+        self.state = state;
+        Some(())
+    }
+
+    // execute, with overflow reported against the line the command came from
+    pub fn run(&mut self, line: usize, command: &Command<N>) -> Result<()> {
+        self.execute(command).ok_or_else(|| Error::Overflow {
+            line: Some(line),
+            source: self.describe(command),
+        })
+    }
+
+    // The command as it would appear in a course file, eg "forward 3"
+    pub fn describe(&self, command: &Command<N>) -> String {
+        match command {
+            Command::Forward(amount) => format!("forward {}", amount),
+            Command::Down(amount) => format!("down {}", amount),
+            Command::Up(amount) => format!("up {}", amount),
+            Command::Extra(i, _) if !self.extras[*i].takes_amount() => {
+                self.extras[*i].verb().to_string()
+            }
+            Command::Extra(i, amount) => format!("{} {}", self.extras[*i].verb(), amount),
+        }
+    }
+
+    pub fn state(&self) -> &State<N> {
+        &self.state
+    }
+
+    // pos * depth, the puzzle answer
+    pub fn product(&self) -> Result<N> {
+        self.state
+            .pos
+            .checked_mul(&self.state.depth)
+            .ok_or_else(|| Error::Overflow {
+                line: None,
+                source: "pos * depth".to_string(),
+            })
+    }
+}
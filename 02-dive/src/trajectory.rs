@@ -0,0 +1,116 @@
+// Every state the submarine passes through on a course, for working out where a course file
+// goes wrong.
+use crate::{Command, Number, State, Submarine};
+use aoc_core::Result;
+use std::fmt::Write;
+
+pub struct Step<N = i32> {
+    // 0 is where the submarine started, n is after the nth command
+    pub index: usize,
+    // 1-based line in the course file, 0 for the start
+    pub line: usize,
+    // As written in the course file, empty for the start
+    pub command: String,
+    pub state: State<N>,
+}
+
+pub struct Trajectory<N = i32> {
+    steps: Vec<Step<N>>,
+}
+
+impl<N: Number> Trajectory<N> {
+    // Runs commands (with the lines they came from) on submarine, keeping the state after
+    // each one. Stops at the first overflow.
+    pub fn record(
+        submarine: &mut Submarine<N>,
+        commands: &[(usize, Command<N>)],
+    ) -> Result<Trajectory<N>> {
+        let mut steps = Vec::with_capacity(commands.len() + 1);
+        steps.push(Step {
+            index: 0,
+            line: 0,
+            command: String::new(),
+            state: submarine.state().clone(),
+        });
+        for (i, (line, command)) in commands.iter().enumerate() {
+            submarine.run(*line, command)?;
+            steps.push(Step {
+                index: i + 1,
+                line: *line,
+                command: submarine.describe(command),
+                state: submarine.state().clone(),
+            });
+        }
+        Ok(Trajectory { steps })
+    }
+
+    pub fn steps(&self) -> &[Step<N>] {
+        &self.steps
+    }
+
+    // The first step at the deepest point
+    pub fn max_depth(&self) -> &Step<N> {
+        self.steps
+            .iter()
+            .reduce(|deepest, step| {
+                if step.state.depth > deepest.state.depth {
+                    step
+                } else {
+                    deepest
+                }
+            })
+            .unwrap()
+    }
+
+    pub fn first_deeper_than(&self, threshold: &N) -> Option<&Step<N>> {
+        self.steps.iter().find(|step| step.state.depth > *threshold)
+    }
+
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("step,command,pos,depth,aim\n");
+        for step in &self.steps {
+            let State { pos, depth, aim } = &step.state;
+            writeln!(
+                csv,
+                "{},{},{},{},{}",
+                step.index, step.command, pos, depth, aim
+            )
+            .unwrap();
+        }
+        csv
+    }
+
+    // Depth goes down the page, like it does in the sea. The axes are scaled separately, as
+    // depth is often thousands of times the distance travelled.
+    pub fn to_svg(&self) -> String {
+        let range = |value: fn(&State<N>) -> &N| {
+            let values = self.steps.iter().map(|s| value(&s.state).to_f64());
+            let min = values.clone().fold(f64::INFINITY, f64::min);
+            let max = values.fold(f64::NEG_INFINITY, f64::max);
+            (min, (max - min).max(1.0))
+        };
+        let (min_pos, width) = range(|s| &s.pos);
+        let (min_depth, height) = range(|s| &s.depth);
+
+        let points: Vec<String> = self
+            .steps
+            .iter()
+            .map(|s| format!("{},{}", s.state.pos, s.state.depth))
+            .collect();
+        let mut svg = String::new();
+        writeln!(
+            svg,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="800" height="400" viewBox="{} {} {} {}" preserveAspectRatio="none">"#,
+            min_pos, min_depth, width, height
+        )
+        .unwrap();
+        writeln!(
+            svg,
+            r#"  <polyline fill="none" stroke="navy" stroke-width="2" vector-effect="non-scaling-stroke" points="{}"/>"#,
+            points.join(" ")
+        )
+        .unwrap();
+        svg.push_str("</svg>\n");
+        svg
+    }
+}
@@ -0,0 +1,65 @@
+use dive::{lint, Aim, Naive, Severity, Submarine};
+
+fn findings(submarine: Submarine<i32>, input: &str) -> Vec<(usize, Severity)> {
+    lint(submarine, input)
+        .iter()
+        .map(|f| (f.line, f.severity))
+        .collect()
+}
+
+#[test]
+fn clean_course_has_no_findings() {
+    let input = "forward 5\ndown 5\nforward 8\nup 3\ndown 8\nforward 2\n";
+    assert!(lint(Submarine::<i32>::new(Aim), input).is_empty());
+}
+
+#[test]
+fn every_bad_line_is_listed() {
+    let input = "forward 5\nsideways 3\nforward\n\ndown 2 2\ndown 1\n";
+    assert_eq!(
+        findings(Submarine::new(Aim), input),
+        [
+            (2, Severity::Error),
+            (3, Severity::Error),
+            (5, Severity::Error)
+        ]
+    );
+    let first = &lint(Submarine::<i32>::new(Aim), input)[0];
+    assert!(first.to_string().starts_with("error: line 2, column 1"));
+}
+
+#[test]
+fn suspicious_lines_are_warnings() {
+    let input = "forward 5 \ndown 0\nup 2\nforward -1\nforward 3\n";
+    let found = lint(Submarine::<i32>::new(Aim), input);
+    let messages: Vec<String> = found.iter().map(|f| f.to_string()).collect();
+    assert_eq!(
+        messages,
+        [
+            "warning: line 1: trailing whitespace",
+            "warning: line 2: amount is 0, so this does nothing",
+            "warning: line 3: aim goes negative (-2), pointing the submarine up",
+            "warning: line 4: negative amount -1, which reverses the command",
+            "warning: line 5: depth goes negative (-4), above the surface",
+        ]
+    );
+}
+
+#[test]
+fn semantics_decide_what_goes_negative() {
+    let input = "up 3\nup 1\n";
+    assert_eq!(
+        findings(Submarine::new(Naive), input),
+        [(1, Severity::Warning)]
+    );
+    assert_eq!(
+        lint(Submarine::<i32>::new(Naive), input)[0].to_string(),
+        "warning: line 1: depth goes negative (-3), above the surface"
+    );
+}
+
+#[test]
+fn overflow_is_an_error() {
+    let input = "down 2000000000\nforward 2\n";
+    assert_eq!(findings(Submarine::new(Aim), input), [(2, Severity::Error)]);
+}
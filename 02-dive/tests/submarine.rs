@@ -0,0 +1,109 @@
+use aoc_core::Error;
+use dive::{
+    submarine::{Back, Surface, Turn},
+    Aim, Command, Instruction, Naive, State, Submarine,
+};
+use num_bigint::BigInt;
+
+const EXAMPLE: [&str; 6] = [
+    "forward 5",
+    "down 5",
+    "forward 8",
+    "up 3",
+    "down 8",
+    "forward 2",
+];
+
+fn run(mut submarine: Submarine, lines: &[&str]) -> State {
+    for line in lines {
+        let command = submarine.parse(line).unwrap();
+        submarine.execute(&command).unwrap();
+    }
+    submarine.state().clone()
+}
+
+#[test]
+fn naive_and_aim_semantics() {
+    let state = run(Submarine::new(Naive), &EXAMPLE);
+    assert_eq!((state.pos, state.depth), (15, 10));
+
+    let state = run(Submarine::new(Aim), &EXAMPLE);
+    assert_eq!((state.pos, state.depth, state.aim), (15, 60, 10));
+}
+
+#[test]
+fn registered_commands() {
+    let submarine = Submarine::new(Aim)
+        .register(Back)
+        .register(Surface)
+        .register(Turn);
+    let state = run(
+        submarine,
+        &["down 2", "forward 4", "turn", "forward 1", "back 3"],
+    );
+    assert_eq!(
+        state,
+        State {
+            pos: 2,
+            depth: 6,
+            aim: -2
+        }
+    );
+
+    let submarine = Submarine::new(Aim).register(Surface);
+    assert_eq!(run(submarine, &["down 2", "forward 4", "surface"]).depth, 0);
+}
+
+struct Dive;
+
+impl Instruction<i32> for Dive {
+    fn verb(&self) -> &str {
+        "dive"
+    }
+    fn apply(&self, state: &mut State, amount: &i32) -> Option<()> {
+        state.depth += amount * 10;
+        Some(())
+    }
+}
+
+#[test]
+fn custom_instruction() {
+    let submarine = Submarine::new(Naive).register(Dive);
+    assert_eq!(submarine.parse("dive 2"), Ok(Command::Extra(0, 2)));
+    assert_eq!(run(submarine, &["dive 2", "up 1"]).depth, 19);
+}
+
+#[test]
+fn parse_errors() {
+    let submarine = Submarine::<i32>::new(Naive).register(Surface);
+    let e = submarine.parse("sideways 3").unwrap_err();
+    assert_eq!(e.expected, "forward, down, up or surface");
+    assert_eq!(submarine.parse("surface 3").unwrap_err().column, 9);
+    assert_eq!(
+        submarine.parse("forward").unwrap_err().expected,
+        "an amount"
+    );
+}
+
+#[test]
+fn overflow_is_reported_with_its_line() {
+    let mut submarine = Submarine::<i32>::new(Aim);
+    submarine.run(1, &Command::Down(i32::MAX)).unwrap();
+    match submarine.run(7, &Command::Forward(2)) {
+        Err(Error::Overflow { line, source }) => {
+            assert_eq!(line, Some(7));
+            assert_eq!(source, "forward 2");
+        }
+        other => panic!("expected an overflow, got {:?}", other),
+    }
+    // The failed command doesn't half happen
+    assert_eq!(submarine.state().pos, 0);
+
+    let mut submarine = Submarine::<BigInt>::new(Aim);
+    let down = submarine.parse("down 99999999999999999999").unwrap();
+    submarine.execute(&down).unwrap();
+    submarine
+        .execute(&Command::Forward(BigInt::from(10)))
+        .unwrap();
+    assert_eq!(submarine.state().depth.to_string(), "999999999999999999990");
+}
@@ -0,0 +1,55 @@
+use dive::{Aim, Command, Submarine, Trajectory};
+
+fn example() -> Trajectory {
+    let mut submarine = Submarine::new(Aim);
+    let commands: Vec<(usize, Command)> = [
+        "forward 5",
+        "down 5",
+        "forward 8",
+        "up 3",
+        "down 8",
+        "forward 2",
+    ]
+    .iter()
+    .enumerate()
+    .map(|(i, line)| (i + 1, submarine.parse(line).unwrap()))
+    .collect();
+    Trajectory::record(&mut submarine, &commands).unwrap()
+}
+
+#[test]
+fn records_every_step() {
+    let trajectory = example();
+    assert_eq!(trajectory.steps().len(), 7);
+    let step = &trajectory.steps()[3];
+    assert_eq!(step.index, 3);
+    assert_eq!(step.command, "forward 8");
+    assert_eq!(step.line, 3);
+    assert_eq!(
+        (step.state.pos, step.state.depth, step.state.aim),
+        (13, 40, 5)
+    );
+}
+
+#[test]
+fn depth_queries() {
+    let trajectory = example();
+    assert_eq!(trajectory.max_depth().index, 6);
+    assert_eq!(trajectory.max_depth().state.depth, 60);
+    assert_eq!(trajectory.first_deeper_than(&0).map(|s| s.index), Some(3));
+    assert!(trajectory.first_deeper_than(&60).is_none());
+}
+
+#[test]
+fn csv_and_svg() {
+    let trajectory = example();
+    let csv = trajectory.to_csv();
+    let lines: Vec<&str> = csv.lines().collect();
+    assert_eq!(lines[0], "step,command,pos,depth,aim");
+    assert_eq!(lines[1], "0,,0,0,0");
+    assert_eq!(lines[7], "6,forward 2,15,60,10");
+
+    let svg = trajectory.to_svg();
+    assert!(svg.contains(r#"viewBox="0 0 15 60""#));
+    assert!(svg.contains(r#"points="0,0 5,0 5,0 13,40 13,40 13,40 15,60""#));
+}
@@ -0,0 +1,91 @@
+// How to pick a bit from a column of a report. Gamma and the oxygen rating keep the most
+// common bit, epsilon and the CO2 rating keep the least common one, so every part of the
+// puzzle goes through the same rules and they can't disagree about what a tie means.
+use crate::{Report, SearchFailure, Trie};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Keep {
+    MostCommon,
+    LeastCommon,
+}
+
+// Which columns a rating looks at first
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BitOrder {
+    MsbFirst,
+    LsbFirst,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RatingCriteria {
+    pub keep: Keep,
+    // The bit kept when the ones are exactly at the threshold
+    pub tie: bool,
+    pub order: BitOrder,
+    // The fraction of ones above which 1 is the most common bit
+    pub threshold: f64,
+}
+
+impl RatingCriteria {
+    // Gamma and the oxygen generator rating: ties go to 1
+    pub const fn most_common() -> Self {
+        RatingCriteria {
+            keep: Keep::MostCommon,
+            tie: true,
+            order: BitOrder::MsbFirst,
+            threshold: 0.5,
+        }
+    }
+
+    // Epsilon and the CO2 scrubber rating: ties go to 0
+    pub const fn least_common() -> Self {
+        RatingCriteria {
+            keep: Keep::LeastCommon,
+            tie: false,
+            order: BitOrder::MsbFirst,
+            threshold: 0.5,
+        }
+    }
+
+    pub fn tie(self, tie: bool) -> Self {
+        RatingCriteria { tie, ..self }
+    }
+
+    pub fn order(self, order: BitOrder) -> Self {
+        RatingCriteria { order, ..self }
+    }
+
+    pub fn threshold(self, threshold: f64) -> Self {
+        RatingCriteria { threshold, ..self }
+    }
+
+    // The bit to keep, given how many of total bits are ones
+    pub fn bit(&self, ones: usize, total: usize) -> bool {
+        use std::cmp::Ordering::*;
+        let most_common = match (ones as f64).partial_cmp(&(self.threshold * total as f64)) {
+            Some(Greater) => true,
+            Some(Less) => false,
+            _ => return self.tie,
+        };
+        return match self.keep {
+            Keep::MostCommon => most_common,
+            Keep::LeastCommon => !most_common,
+        };
+    }
+
+    // The bit kept in each column over the whole report, most significant first
+    pub fn common_bits(&self, report: &Report) -> Vec<bool> {
+        report
+            .column_counts()
+            .into_iter()
+            .map(|ones| self.bit(ones, report.rows()))
+            .collect()
+    }
+
+    // Narrows the report down a column at a time, keeping the rows that have the bit these
+    // criteria pick among the rows still left, until one row remains. Returns that row.
+    // Build a Trie to take more than one rating from the same report.
+    pub fn rating(&self, report: &Report) -> Result<usize, SearchFailure> {
+        Trie::new(report, self.order).rating(self)
+    }
+}
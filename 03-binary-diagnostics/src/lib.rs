@@ -0,0 +1,48 @@
+pub mod bitset;
+pub mod criteria;
+mod pt1;
+mod pt2;
+pub mod radix;
+pub mod report;
+pub mod trie;
+
+pub use bitset::Bitset;
+pub use criteria::{BitOrder, Keep, RatingCriteria};
+pub use pt1::Part1;
+pub use pt2::Part2;
+pub use radix::Radix;
+pub use report::Report;
+pub use trie::{SearchFailure, Trie};
+
+use aoc_core::{Error, Result, Value};
+use num_bigint::BigUint;
+
+// Converts a binary number, most significant bit first, to a number
+pub fn to_u128(b: &[bool]) -> Result<u128> {
+    if b.len() > 128 {
+        return Err(Error::Overflow {
+            line: None,
+            source: format!("a {} bit number", b.len()),
+        });
+    }
+    let mut n = 0;
+    for &digit in b {
+        n <<= 1;
+        n += u128::from(digit)
+    }
+    return Ok(n);
+}
+
+// Like to_u128, for numbers of any width
+pub fn to_biguint(b: &[bool]) -> BigUint {
+    let digits: Vec<u8> = b.iter().map(|&digit| digit as u8).collect();
+    BigUint::from_radix_be(&digits, 2).unwrap()
+}
+
+// Reports as a number while it fits, digits after that
+fn value(n: &BigUint) -> Value {
+    match u128::try_from(n) {
+        Ok(n) => n.into(),
+        Err(_) => Value::Text(n.to_string()),
+    }
+}
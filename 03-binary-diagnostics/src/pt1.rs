@@ -0,0 +1,29 @@
+use crate::{to_biguint, value, Radix, RatingCriteria, Report};
+use aoc_core::{debug, Answer, Result, Solver};
+
+// Rows are binary unless another radix is given
+#[derive(Default)]
+pub struct Part1 {
+    pub radix: Radix,
+}
+
+impl Solver for Part1 {
+    type Input = Report;
+
+    fn parse(&self, input: &str) -> Result<Report> {
+        debug!("Reading input");
+        Report::read_radix(input.as_bytes(), self.radix)
+    }
+
+    fn solve(&self, report: &Report) -> Result<Answer> {
+        let gamma_digits = RatingCriteria::most_common().common_bits(report);
+        let epsilon_digits = RatingCriteria::least_common().common_bits(report);
+
+        let gamma = to_biguint(&gamma_digits);
+        let epsilon = to_biguint(&epsilon_digits);
+
+        Ok(Answer::new(value(&(&gamma * &epsilon)))
+            .with("gamma", value(&gamma))
+            .with("epsilon", value(&epsilon)))
+    }
+}
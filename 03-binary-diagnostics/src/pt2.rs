@@ -0,0 +1,36 @@
+use crate::{to_biguint, value, BitOrder, Radix, RatingCriteria, Report, Trie};
+use aoc_core::{debug, Answer, Error, Result, Solver};
+
+// Rows are binary unless another radix is given
+#[derive(Default)]
+pub struct Part2 {
+    pub radix: Radix,
+}
+
+impl Solver for Part2 {
+    type Input = Report;
+
+    fn parse(&self, input: &str) -> Result<Report> {
+        debug!("Reading input");
+        Report::read_radix(input.as_bytes(), self.radix)
+    }
+
+    fn solve(&self, report: &Report) -> Result<Answer> {
+        let trie = Trie::new(report, BitOrder::MsbFirst);
+        debug!("Calculating oxy");
+        let oxy_row = trie
+            .rating(&RatingCriteria::most_common())
+            .map_err(|e| Error::NoSolution(format!("oxygen generator rating: {}", e)))?;
+        debug!("Calculating co2");
+        let co2_row = trie
+            .rating(&RatingCriteria::least_common())
+            .map_err(|e| Error::NoSolution(format!("CO2 scrubber rating: {}", e)))?;
+
+        let oxy = to_biguint(&report.row(oxy_row));
+        let co2 = to_biguint(&report.row(co2_row));
+
+        Ok(Answer::new(value(&(&oxy * &co2)))
+            .with("oxy", value(&oxy))
+            .with("co2", value(&co2)))
+    }
+}
@@ -0,0 +1,61 @@
+// The base a report's rows are written in. Every digit stands for a fixed number of bits, so
+// an octal or hex row is read into the same columns a binary one would be.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Radix {
+    #[default]
+    Binary,
+    Octal,
+    Hex,
+}
+
+impl Radix {
+    pub fn from_name(name: &str) -> Option<Radix> {
+        match name {
+            "bin" | "binary" | "2" => Some(Radix::Binary),
+            "oct" | "octal" | "8" => Some(Radix::Octal),
+            "hex" | "16" => Some(Radix::Hex),
+            _ => None,
+        }
+    }
+
+    pub fn base(&self) -> u32 {
+        match self {
+            Radix::Binary => 2,
+            Radix::Octal => 8,
+            Radix::Hex => 16,
+        }
+    }
+
+    pub fn bits_per_digit(&self) -> usize {
+        match self {
+            Radix::Binary => 1,
+            Radix::Octal => 3,
+            Radix::Hex => 4,
+        }
+    }
+
+    // What a single digit is called in errors
+    pub fn digit_name(&self) -> &'static str {
+        match self {
+            Radix::Binary => "'0' or '1'",
+            Radix::Octal => "an octal digit",
+            Radix::Hex => "a hex digit",
+        }
+    }
+
+    // What a row of n digits is called in errors
+    pub fn row_name(&self, digits: usize) -> String {
+        match self {
+            Radix::Binary => format!("a row of {} bits", digits),
+            Radix::Octal => format!("a row of {} octal digits", digits),
+            Radix::Hex => format!("a row of {} hex digits", digits),
+        }
+    }
+
+    // The bits of digit c, most significant first
+    pub fn bits(&self, c: char) -> Option<impl Iterator<Item = bool>> {
+        let value = c.to_digit(self.base())?;
+        let n = self.bits_per_digit();
+        Some((0..n).rev().map(move |i| value & (1 << i) != 0))
+    }
+}
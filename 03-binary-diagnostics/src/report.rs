@@ -0,0 +1,96 @@
+// A diagnostic report, stored a column at a time: column c is a bitset over the rows, with
+// bit r set if row r has a 1 in that column. Counting the ones in a column is then a
+// popcount per 64 rows, and narrowing down to the rows that match a bit is an and per 64
+// rows. Columns are numbered from the left, so column 0 is the most significant bit.
+//
+// Nothing is limited to 64 bits: a report can be any width, and have any number of rows.
+// Rows can also be written in octal or hex, each digit adding 3 or 4 columns.
+use crate::{Bitset, Radix};
+use aoc_core::{parse, ParseError, Result};
+use std::io::BufRead;
+
+pub struct Report {
+    radix: Radix,
+    rows: usize,
+    columns: Vec<Bitset>,
+}
+
+impl Report {
+    // One row of '0's and '1's per line, all the same width
+    pub fn read<R: BufRead>(reader: R) -> Result<Report> {
+        Self::read_radix(reader, Radix::Binary)
+    }
+
+    // One row of digits in radix per line, all the same width
+    pub fn read_radix<R: BufRead>(reader: R, radix: Radix) -> Result<Report> {
+        let mut report = Report {
+            radix,
+            rows: 0,
+            columns: Vec::new(),
+        };
+        parse::map_lines(reader, |line| report.push_row(line))?;
+        Ok(report)
+    }
+
+    fn push_row(&mut self, line: &str) -> Result<(), ParseError> {
+        let bits_per_digit = self.radix.bits_per_digit();
+        if self.rows == 0 {
+            self.columns = vec![Bitset::new(0); line.chars().count() * bits_per_digit];
+        }
+        let mut width = 0;
+        for (i, c) in line.char_indices() {
+            let bits = match self.radix.bits(c) {
+                Some(bits) => bits,
+                None => {
+                    let token = &line[i..i + c.len_utf8()];
+                    return Err(ParseError::new(line, token, self.radix.digit_name()));
+                }
+            };
+            if width >= self.width() {
+                return Err(self.width_error(line));
+            }
+            for bit in bits {
+                self.columns[width].push(bit);
+                width += 1;
+            }
+        }
+        if width != self.width() {
+            return Err(self.width_error(line));
+        }
+        self.rows += 1;
+        Ok(())
+    }
+
+    // Columns that were pushed to before the bad row are left a bit longer than the others,
+    // but the report is thrown away when that happens
+    fn width_error(&self, line: &str) -> ParseError {
+        let digits = self.width() / self.radix.bits_per_digit();
+        ParseError::new(line, line, self.radix.row_name(digits))
+    }
+
+    pub fn radix(&self) -> Radix {
+        self.radix
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn width(&self) -> usize {
+        self.columns.len()
+    }
+
+    pub fn column(&self, c: usize) -> &Bitset {
+        &self.columns[c]
+    }
+
+    // How many rows have a 1 in each column
+    pub fn column_counts(&self) -> Vec<usize> {
+        self.columns.iter().map(|c| c.count_ones()).collect()
+    }
+
+    // Row r, most significant bit first
+    pub fn row(&self, r: usize) -> Vec<bool> {
+        self.columns.iter().map(|c| c.get(r)).collect()
+    }
+}
@@ -0,0 +1,151 @@
+// The rows of a report as a binary trie, one level per column, with the number of rows under
+// each node. A rating is then a single walk from the root: at each node the counts of its two
+// children say which bit is most common among the rows still in the running, and the walk
+// stops as soon as a node has only one row under it. So a row only goes as deep as it needs
+// to: it stops at the first node nobody else has reached, and is pushed down a level when a
+// second row arrives there.
+use crate::{BitOrder, RatingCriteria, Report};
+use std::fmt;
+
+// Why a rating didn't narrow down to a single row
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SearchFailure {
+    Empty,
+    // The criteria kept a bit that none of the remaining rows have
+    NoneLeft {
+        column: usize,
+        bit: bool,
+        remaining: usize,
+    },
+    // Every column was used and these rows are still left, so they're all the same
+    Duplicates {
+        rows: usize,
+    },
+}
+
+impl fmt::Display for SearchFailure {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SearchFailure::Empty => write!(f, "the report is empty"),
+            SearchFailure::NoneLeft {
+                column,
+                bit,
+                remaining,
+            } => write!(
+                f,
+                "column {} keeps {}s, but none of the {} rows left have one there",
+                column, *bit as u8, remaining
+            ),
+            SearchFailure::Duplicates { rows } => {
+                write!(f, "{} identical rows are left after the last column", rows)
+            }
+        }
+    }
+}
+
+struct Node {
+    // Index of the child for a 0 and a 1, 0 if there isn't one (the root is nobody's child)
+    children: [usize; 2],
+    count: usize,
+    // The first row that went through here, the only one if count is 1
+    row: usize,
+}
+
+impl Node {
+    fn new(row: usize) -> Self {
+        Node {
+            children: [0; 2],
+            count: 0,
+            row,
+        }
+    }
+}
+
+pub struct Trie {
+    columns: Vec<usize>,
+    nodes: Vec<Node>,
+}
+
+impl Trie {
+    // Columns go into the trie in order, so the ratings walked from it look at them that way
+    pub fn new(report: &Report, order: BitOrder) -> Self {
+        let columns: Vec<usize> = match order {
+            BitOrder::MsbFirst => (0..report.width()).collect(),
+            BitOrder::LsbFirst => (0..report.width()).rev().collect(),
+        };
+        let bit = |row: usize, depth: usize| report.column(columns[depth]).get(row) as usize;
+        let mut nodes = vec![Node::new(0)];
+        for row in 0..report.rows() {
+            let mut node = 0;
+            for depth in 0..=columns.len() {
+                nodes[node].count += 1;
+                if nodes[node].count == 1 {
+                    nodes[node].row = row;
+                    break;
+                }
+                // Past the last column only duplicates are left to count
+                if depth == columns.len() {
+                    break;
+                }
+                // This was a leaf until now, its row moves down to make room
+                if nodes[node].count == 2 {
+                    let leaf = Node {
+                        count: 1,
+                        ..Node::new(nodes[node].row)
+                    };
+                    nodes.push(leaf);
+                    let b = bit(nodes[node].row, depth);
+                    nodes[node].children[b] = nodes.len() - 1;
+                }
+                let b = bit(row, depth);
+                if nodes[node].children[b] == 0 {
+                    nodes.push(Node::new(row));
+                    nodes[node].children[b] = nodes.len() - 1;
+                }
+                node = nodes[node].children[b];
+            }
+        }
+        Trie { columns, nodes }
+    }
+
+    // Nodes in the trie, root included
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    fn count(&self, node: usize) -> usize {
+        if node == 0 {
+            return 0;
+        }
+        return self.nodes[node].count;
+    }
+
+    // The row these criteria narrow the report down to. Columns are walked in the order the trie
+    // was built with; criteria.order only picks that order in RatingCriteria::rating.
+    pub fn rating(&self, criteria: &RatingCriteria) -> Result<usize, SearchFailure> {
+        let mut node = &self.nodes[0];
+        if node.count == 0 {
+            return Err(SearchFailure::Empty);
+        }
+        for &column in &self.columns {
+            if node.count == 1 {
+                return Ok(node.row);
+            }
+            let ones = self.count(node.children[1]);
+            let bit = criteria.bit(ones, node.count);
+            let child = node.children[bit as usize];
+            if child == 0 {
+                return Err(SearchFailure::NoneLeft {
+                    column,
+                    bit,
+                    remaining: node.count,
+                });
+            }
+            node = &self.nodes[child];
+        }
+        if node.count == 1 {
+            return Ok(node.row);
+        }
+        return Err(SearchFailure::Duplicates { rows: node.count });
+    }
+}
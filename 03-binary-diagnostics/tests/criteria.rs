@@ -0,0 +1,40 @@
+use binary_diagnostics::{BitOrder, RatingCriteria, Report};
+
+const EXAMPLE: &str =
+    "00100\n11110\n10110\n10111\n10101\n01111\n00111\n11100\n10000\n11001\n00010\n01010\n";
+
+#[test]
+fn ties_and_odd_counts() {
+    let most = RatingCriteria::most_common();
+    let least = RatingCriteria::least_common();
+    assert!(most.bit(2, 4));
+    assert!(!least.bit(2, 4));
+    // One in three is the least common bit, which `count < rows / 2` used to get wrong
+    assert!(!most.bit(1, 3));
+    assert!(least.bit(1, 3));
+    assert!(!most.tie(false).bit(2, 4));
+    assert!(!most.threshold(0.75).bit(2, 3));
+}
+
+#[test]
+fn gamma_and_epsilon_are_complements() {
+    let report = Report::read("101\n011\n000\n110\n".as_bytes()).unwrap();
+    let gamma = RatingCriteria::most_common().common_bits(&report);
+    let epsilon = RatingCriteria::least_common().common_bits(&report);
+    // Every column is a tie here
+    assert_eq!(gamma, [true, true, true]);
+    assert!(gamma.iter().zip(&epsilon).all(|(g, e)| g != e));
+}
+
+#[test]
+fn ratings() {
+    let report = Report::read(EXAMPLE.as_bytes()).unwrap();
+    let oxy = RatingCriteria::most_common().rating(&report).unwrap();
+    let co2 = RatingCriteria::least_common().rating(&report).unwrap();
+    assert_eq!(report.row(oxy), [true, false, true, true, true]);
+    assert_eq!(report.row(co2), [false, true, false, true, false]);
+
+    let lsb = RatingCriteria::most_common().order(BitOrder::LsbFirst);
+    let row = lsb.rating(&report).unwrap();
+    assert_eq!(report.row(row), [true, true, true, true, false]);
+}
@@ -0,0 +1,58 @@
+use aoc_core::{Error, Solver, Value};
+use binary_diagnostics::{to_biguint, to_u128, Part1, Radix, RatingCriteria, Report};
+
+#[test]
+fn hex_and_octal_rows_are_read_as_bits() {
+    let report = Report::read_radix("a5\n0F\n".as_bytes(), Radix::Hex).unwrap();
+    assert_eq!(report.width(), 8);
+    assert_eq!(to_u128(&report.row(0)).unwrap(), 0xa5);
+    assert_eq!(to_u128(&report.row(1)).unwrap(), 0x0f);
+
+    let report = Report::read_radix("17\n".as_bytes(), Radix::Octal).unwrap();
+    assert_eq!(report.row(0), [false, false, true, true, true, true]);
+}
+
+#[test]
+fn rows_must_be_the_same_width_and_radix() {
+    match Report::read_radix("abc\nabcd\n".as_bytes(), Radix::Hex) {
+        Err(Error::Parse(e)) => assert_eq!(e.expected, "a row of 3 hex digits"),
+        other => panic!("expected a parse error, got {:?}", other.err()),
+    }
+    match Report::read_radix("178\n".as_bytes(), Radix::Octal) {
+        Err(Error::Parse(e)) => {
+            assert_eq!((e.token.as_str(), e.column), ("8", 3));
+            assert_eq!(e.expected, "an octal digit");
+        }
+        other => panic!("expected a parse error, got {:?}", other.err()),
+    }
+}
+
+#[test]
+fn forty_eight_bit_reports() {
+    let input = "ffff00000000\n0000ffffffff\nf0f0f0f0f0f0\n";
+    let report = Report::read_radix(input.as_bytes(), Radix::Hex).unwrap();
+    let gamma = to_u128(&RatingCriteria::most_common().common_bits(&report)).unwrap();
+    let epsilon = to_u128(&RatingCriteria::least_common().common_bits(&report)).unwrap();
+    assert_eq!(gamma, 0xf0f0f0f0f0f0);
+    assert_eq!(gamma + epsilon, (1 << 48) - 1);
+}
+
+#[test]
+fn solvers_read_their_radix() {
+    let part1 = Part1 { radix: Radix::Hex };
+    let report = part1.parse("a5\n0F\n3c\n").unwrap();
+    let answer = part1.solve(&report).unwrap();
+    assert_eq!(answer.details[0], ("gamma", Value::Int(0x2d)));
+    assert!(Part1::default().parse("a5\n").is_err());
+}
+
+#[test]
+fn wide_numbers() {
+    let bits = vec![true; 129];
+    assert!(matches!(to_u128(&bits), Err(Error::Overflow { .. })));
+    assert_eq!(
+        to_biguint(&bits).to_string(),
+        "680564733841876926926749214863536422911"
+    );
+    assert_eq!(to_u128(&bits[1..]).unwrap(), u128::MAX);
+}
@@ -0,0 +1,34 @@
+use aoc_core::Error;
+use binary_diagnostics::Report;
+
+#[test]
+fn column_counts() {
+    let report = Report::read("00100\n11110\n10110\n".as_bytes()).unwrap();
+    assert_eq!((report.rows(), report.width()), (3, 5));
+    assert_eq!(report.column_counts(), [2, 1, 3, 2, 0]);
+    assert_eq!(report.row(1), [true, true, true, true, false]);
+}
+
+#[test]
+fn wide_reports() {
+    let row = "1".repeat(100) + "0";
+    let input = format!("{}\n{}\n", row, "0".repeat(101));
+    let report = Report::read(input.as_bytes()).unwrap();
+    assert_eq!(report.width(), 101);
+    assert_eq!(report.column_counts()[99..], [1, 0]);
+}
+
+#[test]
+fn bad_rows_are_reported() {
+    match Report::read("0101\n011\n".as_bytes()) {
+        Err(Error::Parse(e)) => {
+            assert_eq!(e.line, Some(2));
+            assert_eq!(e.expected, "a row of 4 bits");
+        }
+        other => panic!("expected a parse error, got {:?}", other.err()),
+    }
+    match Report::read("0101\n01201\n".as_bytes()) {
+        Err(Error::Parse(e)) => assert_eq!((e.token.as_str(), e.column), ("2", 3)),
+        other => panic!("expected a parse error, got {:?}", other.err()),
+    }
+}
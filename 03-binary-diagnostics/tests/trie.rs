@@ -0,0 +1,69 @@
+use binary_diagnostics::{BitOrder, RatingCriteria, Report, SearchFailure, Trie};
+
+fn report(input: &str) -> Report {
+    Report::read(input.as_bytes()).unwrap()
+}
+
+#[test]
+fn one_trie_for_both_ratings() {
+    let report = report(
+        "00100\n11110\n10110\n10111\n10101\n01111\n00111\n11100\n10000\n11001\n00010\n01010\n",
+    );
+    let trie = Trie::new(&report, BitOrder::MsbFirst);
+    assert_eq!(trie.rating(&RatingCriteria::most_common()), Ok(3));
+    assert_eq!(trie.rating(&RatingCriteria::least_common()), Ok(11));
+
+    // The trie's own order wins over the criteria's
+    let lsb = Trie::new(&report, BitOrder::LsbFirst);
+    let most = RatingCriteria::most_common();
+    assert_eq!(
+        lsb.rating(&most),
+        most.order(BitOrder::LsbFirst).rating(&report)
+    );
+}
+
+#[test]
+fn failures_say_why() {
+    let most = RatingCriteria::most_common();
+    let least = RatingCriteria::least_common();
+    assert_eq!(most.rating(&report("")), Err(SearchFailure::Empty));
+
+    let trie = Trie::new(&report("101\n101\n100\n"), BitOrder::MsbFirst);
+    assert_eq!(
+        trie.rating(&most),
+        Err(SearchFailure::Duplicates { rows: 2 })
+    );
+    let e = trie.rating(&least).unwrap_err();
+    assert_eq!(
+        e,
+        SearchFailure::NoneLeft {
+            column: 0,
+            bit: false,
+            remaining: 3
+        }
+    );
+    assert_eq!(
+        e.to_string(),
+        "column 0 keeps 0s, but none of the 3 rows left have one there"
+    );
+}
+
+#[test]
+fn single_row_needs_no_columns() {
+    let report = report("0110\n");
+    assert_eq!(RatingCriteria::least_common().rating(&report), Ok(0));
+}
+
+#[test]
+fn rows_stop_where_they_differ() {
+    // 200 bits wide, but the rows part ways in the first two columns
+    let rows = ["00", "01", "10", "11", "11"].map(|prefix| prefix.to_string() + &"1".repeat(198));
+    let trie = Trie::new(&report(&(rows.join("\n") + "\n")), BitOrder::MsbFirst);
+    // Root, two halves, three single-row leaves and the "11" duplicates all the way down
+    assert_eq!(trie.node_count(), 6 + 199);
+    assert_eq!(
+        trie.rating(&RatingCriteria::most_common()),
+        Err(SearchFailure::Duplicates { rows: 2 })
+    );
+    assert_eq!(trie.rating(&RatingCriteria::least_common()), Ok(0));
+}
@@ -0,0 +1,168 @@
+// Questions about a game that playing it once doesn't answer: how soon each board could win
+// if the calls came in the best order for it, how the boards rank for the calls as given, and
+// an order of the same calls that makes a chosen board come first or last.
+use crate::{BingoNumber, Board, Game, Pattern, Rules, Timeline};
+use std::collections::HashSet;
+
+// The distinct numbers of each pattern on board that can complete at all, ie whose numbers
+// are all called at some point
+fn reachable(
+    board: &Board,
+    patterns: &[Pattern],
+    called: &HashSet<BingoNumber>,
+) -> Vec<Vec<BingoNumber>> {
+    patterns
+        .iter()
+        .map(|pattern| {
+            let mut numbers: Vec<BingoNumber> =
+                pattern.cells.iter().map(|&cell| board.get(cell)).collect();
+            numbers.sort();
+            numbers.dedup();
+            numbers
+        })
+        .filter(|numbers| numbers.iter().all(|n| called.contains(n)))
+        .collect()
+}
+
+// A game's boards are normally all one size, but each one gets the patterns for its own
+fn patterns_for(board: &Board, rules: &Rules) -> Vec<Pattern> {
+    rules.patterns(board.rows(), board.cols())
+}
+
+// The fewest calls board could win in, with the called numbers in the best order for it.
+// None if it can't win whatever the order.
+pub fn earliest_win(
+    board: &Board,
+    patterns: &[Pattern],
+    called: &HashSet<BingoNumber>,
+) -> Option<usize> {
+    reachable(board, patterns, called)
+        .iter()
+        .map(|numbers| numbers.len())
+        .min()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Strength {
+    pub board: usize,
+    // The turn it could win on at best, and the turn it does win on with the calls as given
+    pub earliest: Option<usize>,
+    pub turn: Option<usize>,
+}
+
+// Every board, strongest first: in the order they win, then the ones that never do, in board
+// order
+pub fn rank(game: &Game, rules: &Rules) -> Vec<Strength> {
+    let timeline = Timeline::play(game, rules);
+    let called: HashSet<BingoNumber> = game.called.iter().copied().collect();
+    let mut strengths: Vec<Strength> = game
+        .boards
+        .iter()
+        .enumerate()
+        .map(|(b, board)| Strength {
+            board: b,
+            earliest: earliest_win(board, &patterns_for(board, rules), &called),
+            turn: None,
+        })
+        .collect();
+    for win in timeline.wins() {
+        strengths[win.board].turn = Some(win.turn);
+    }
+    let mut ranked: Vec<Strength> = timeline
+        .wins()
+        .map(|win| strengths[win.board].clone())
+        .collect();
+    ranked.extend(timeline.never_won().iter().map(|&b| strengths[b].clone()));
+    return ranked;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Place {
+    First,
+    Last,
+}
+
+// Moves the given numbers to the front (or back) of the calls, keeping the order of the rest
+fn reorder(called: &[BingoNumber], moved: &[BingoNumber], to_front: bool) -> Vec<BingoNumber> {
+    let (mut moving, staying): (Vec<BingoNumber>, Vec<BingoNumber>) =
+        called.iter().partition(|n| moved.contains(n));
+    if to_front {
+        moving.extend(staying);
+        moving
+    } else {
+        let mut reordered = staying;
+        reordered.extend(moving);
+        reordered
+    }
+}
+
+// A set of numbers that leaves none of target's patterns complete, while every one of the
+// other boards still has a pattern it doesn't touch. Found by picking a number from each
+// pattern it misses so far, backing up when that blocks another board completely.
+fn blockers(
+    target: &[Vec<BingoNumber>],
+    others: &[Vec<Vec<BingoNumber>>],
+    chosen: &mut Vec<BingoNumber>,
+) -> bool {
+    let blocked = |numbers: &Vec<BingoNumber>| numbers.iter().any(|n| chosen.contains(n));
+    if others.iter().any(|patterns| patterns.iter().all(blocked)) {
+        return false;
+    }
+    let open = match target.iter().find(|numbers| !blocked(numbers)) {
+        Some(open) => open,
+        None => return true,
+    };
+    for &n in open {
+        chosen.push(n);
+        if blockers(target, others, chosen) {
+            return true;
+        }
+        chosen.pop();
+    }
+    return false;
+}
+
+// The same calls in an order that makes board win before every other board (First), or after
+// every other board that can win at all (Last). Ties don't count. None if there's no such order.
+pub fn arrange(game: &Game, rules: &Rules, board: usize, place: Place) -> Option<Vec<BingoNumber>> {
+    let called: HashSet<BingoNumber> = game.called.iter().copied().collect();
+    let target = game.boards.get(board)?;
+    let target = reachable(target, &patterns_for(target, rules), &called);
+    let others: Vec<Vec<Vec<BingoNumber>>> = game
+        .boards
+        .iter()
+        .enumerate()
+        .filter(|&(b, _)| b != board)
+        .map(|(_, other)| reachable(other, &patterns_for(other, rules), &called))
+        .filter(|patterns| !patterns.is_empty())
+        .collect();
+
+    match place {
+        // Call one of its patterns first. Any other board with a pattern inside those numbers
+        // would win by the same call, so the smallest pattern without one wins.
+        Place::First => {
+            let mut candidates = target;
+            candidates.sort_by_key(|numbers| numbers.len());
+            let first = candidates.into_iter().find(|numbers| {
+                others.iter().all(|patterns| {
+                    !patterns
+                        .iter()
+                        .any(|pattern| pattern.iter().all(|n| numbers.contains(n)))
+                })
+            })?;
+            Some(reorder(&game.called, &first, true))
+        }
+        // Hold back enough numbers to stop it winning while the others all can, and call them
+        // last
+        Place::Last => {
+            if target.is_empty() {
+                return None;
+            }
+            let mut held_back = Vec::new();
+            if !blockers(&target, &others, &mut held_back) {
+                return None;
+            }
+            Some(reorder(&game.called, &held_back, false))
+        }
+    }
+}
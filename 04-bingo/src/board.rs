@@ -0,0 +1,37 @@
+use crate::{BingoNumber, Cell};
+
+// A board of any size, stored a row at a time
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Board {
+    rows: usize,
+    cols: usize,
+    cells: Vec<BingoNumber>,
+}
+
+impl Board {
+    pub fn new(rows: usize, cols: usize, cells: Vec<BingoNumber>) -> Self {
+        assert_eq!(cells.len(), rows * cols, "a {}x{} board", rows, cols);
+        Board { rows, cols, cells }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn get(&self, (row, col): Cell) -> BingoNumber {
+        self.cells[row * self.cols + col]
+    }
+
+    // Every cell and the number in it
+    pub fn cells(&self) -> impl Iterator<Item = (Cell, BingoNumber)> + '_ {
+        let cols = self.cols;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(i, &n)| ((i / cols, i % cols), n))
+    }
+}
@@ -0,0 +1,182 @@
+// Plays a game a call at a time without rescanning anything. Every number is indexed to the
+// cells it's in, and every board keeps a count of the marked cells in each winning pattern,
+// so a call only touches the cells with that number, and a board has won the moment one of
+// its counts reaches the size of its pattern.
+use crate::{BingoNumber, Cell, Game, Pattern, Rules};
+use std::{collections::HashMap, ops::Range};
+
+// A board completing one or more patterns
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Win {
+    // Index into the game's boards
+    pub board: usize,
+    // 1-based, the call that completed it
+    pub turn: usize,
+    pub called: BingoNumber,
+    // Indexes into the engine's patterns, every one completed by this call
+    pub lines: Vec<usize>,
+    // Wide enough that a board of large numbers can't overflow it, nor can its score
+    pub unmarked_sum: i128,
+}
+
+impl Win {
+    pub fn score(&self) -> i128 {
+        self.unmarked_sum * self.called as i128
+    }
+}
+
+// The patterns for one size of board. A game's boards are normally all the same size, but
+// nothing stops a Game built by hand from mixing them.
+struct Layout {
+    rows: usize,
+    cols: usize,
+    // This size's patterns, in the engine's patterns
+    patterns: Range<usize>,
+    // For each cell, row by row, the patterns it's in
+    cell_patterns: Vec<Vec<usize>>,
+}
+
+struct BoardState {
+    layout: usize,
+    marked: Vec<bool>,
+    // Marked cells in each of its layout's patterns
+    hits: Vec<usize>,
+    unmarked_sum: i128,
+    won: bool,
+}
+
+pub struct Engine {
+    layouts: Vec<Layout>,
+    patterns: Vec<Pattern>,
+    // Each number and the (board, cell) it's in
+    index: HashMap<BingoNumber, Vec<(usize, usize)>>,
+    boards: Vec<BoardState>,
+    turn: usize,
+    remaining: usize,
+}
+
+impl Engine {
+    pub fn new(game: &Game, rules: &Rules) -> Self {
+        let mut layouts: Vec<Layout> = Vec::new();
+        let mut patterns = Vec::new();
+        let mut index: HashMap<BingoNumber, Vec<(usize, usize)>> = HashMap::new();
+        let mut boards = Vec::with_capacity(game.boards.len());
+        for (b, board) in game.boards.iter().enumerate() {
+            let (rows, cols) = (board.rows(), board.cols());
+            let layout = match layouts
+                .iter()
+                .position(|l| (l.rows, l.cols) == (rows, cols))
+            {
+                Some(layout) => layout,
+                None => {
+                    let first = patterns.len();
+                    patterns.extend(rules.patterns(rows, cols));
+                    let mut cell_patterns = vec![Vec::new(); rows * cols];
+                    for (i, pattern) in patterns.iter().enumerate().skip(first) {
+                        for &(row, col) in &pattern.cells {
+                            cell_patterns[row * cols + col].push(i);
+                        }
+                    }
+                    layouts.push(Layout {
+                        rows,
+                        cols,
+                        patterns: first..patterns.len(),
+                        cell_patterns,
+                    });
+                    layouts.len() - 1
+                }
+            };
+            for ((row, col), n) in board.cells() {
+                index.entry(n).or_default().push((b, row * cols + col));
+            }
+            boards.push(BoardState {
+                layout,
+                marked: vec![false; rows * cols],
+                hits: vec![0; layouts[layout].patterns.len()],
+                unmarked_sum: board.cells().map(|(_, n)| n as i128).sum(),
+                won: false,
+            });
+        }
+
+        Engine {
+            layouts,
+            patterns,
+            index,
+            remaining: boards.len(),
+            boards,
+            turn: 0,
+        }
+    }
+
+    // Marks n everywhere it appears, and returns the boards that won because of it, in board
+    // order. Boards that already won aren't marked any more.
+    pub fn call(&mut self, n: BingoNumber) -> Vec<Win> {
+        self.turn += 1;
+        let mut wins: Vec<Win> = Vec::new();
+        let cells = match self.index.get(&n) {
+            Some(cells) => cells,
+            None => return wins,
+        };
+        for &(b, cell) in cells {
+            let board = &mut self.boards[b];
+            if board.won || board.marked[cell] {
+                continue;
+            }
+            board.marked[cell] = true;
+            board.unmarked_sum -= n as i128;
+            let mut lines = Vec::new();
+            let layout = &self.layouts[board.layout];
+            for &p in &layout.cell_patterns[cell] {
+                let hits = &mut board.hits[p - layout.patterns.start];
+                *hits += 1;
+                if *hits == self.patterns[p].cells.len() {
+                    lines.push(p);
+                }
+            }
+            if lines.is_empty() {
+                continue;
+            }
+            // The same number twice on a board can complete patterns in two steps
+            match wins.last_mut() {
+                Some(win) if win.board == b => win.lines.extend(lines),
+                _ => wins.push(Win {
+                    board: b,
+                    turn: self.turn,
+                    called: n,
+                    lines,
+                    unmarked_sum: 0,
+                }),
+            }
+        }
+        for win in &mut wins {
+            let board = &mut self.boards[win.board];
+            board.won = true;
+            win.unmarked_sum = board.unmarked_sum;
+        }
+        self.remaining -= wins.len();
+        return wins;
+    }
+
+    // Calls made so far
+    pub fn turn(&self) -> usize {
+        self.turn
+    }
+
+    // Boards that haven't won yet
+    pub fn remaining(&self) -> usize {
+        self.remaining
+    }
+
+    pub fn has_won(&self, board: usize) -> bool {
+        self.boards[board].won
+    }
+
+    pub fn is_marked(&self, board: usize, (row, col): Cell) -> bool {
+        let state = &self.boards[board];
+        state.marked[row * self.layouts[state.layout].cols + col]
+    }
+
+    pub fn patterns(&self) -> &[Pattern] {
+        &self.patterns
+    }
+}
@@ -0,0 +1,130 @@
+use crate::{BingoNumber, Board};
+use aoc_core::{parse, parse::Block, ParseError, Result};
+use std::fmt;
+
+// The called numbers and the boards they're called for. Boards can be any size, as long as
+// they're all the same: the first one decides.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Game {
+    pub called: Vec<BingoNumber>,
+    pub boards: Vec<Board>,
+}
+
+// Rows x columns
+type Size = (usize, usize);
+
+fn parse_row(row: &str, cols: Option<usize>) -> Result<Vec<BingoNumber>, ParseError> {
+    let numbers = row
+        .split_whitespace()
+        .map(|nstr| parse::field::<BingoNumber>(row, nstr))
+        .collect::<Result<Vec<_>, ParseError>>()?;
+    match cols {
+        Some(cols) if numbers.len() != cols => Err(row_length_error(row, cols)),
+        _ => Ok(numbers),
+    }
+}
+
+// Points at the first extra number, or the end of the row if there are too few
+fn row_length_error(row: &str, cols: usize) -> ParseError {
+    let expected = format!("{} numbers per row", cols);
+    match row.split_whitespace().nth(cols) {
+        Some(extra) => ParseError::new(row, extra, expected),
+        None => ParseError::missing(row, expected),
+    }
+}
+
+// Takes the lines of a board and builds a Board, or returns an error. The first board sets
+// the size for the rest.
+fn read_board(block: &Block, size: &mut Option<Size>) -> Result<Board, ParseError> {
+    let mut cells = Vec::new();
+    let mut cols = size.map(|(_, cols)| cols);
+    for (i, line) in block.lines.iter().enumerate() {
+        let row = parse_row(line, cols).map_err(|e| e.at_line(block.start_line + i))?;
+        cols = Some(row.len());
+        cells.extend(row);
+    }
+    let cols = cols.unwrap_or(0);
+    let rows = match *size {
+        Some((rows, _)) => rows,
+        None => block.lines.len(),
+    };
+    if block.lines.len() != rows {
+        // Point at the first extra row, or past the end of the last one
+        let last = block.lines.len().min(rows + 1) - 1;
+        let line = &block.lines[last];
+        let expected = format!("{} rows per board", rows);
+        let e = if last == rows {
+            ParseError::new(line, line, expected)
+        } else {
+            ParseError::missing(line, expected)
+        };
+        return Err(e.at_line(block.start_line + last));
+    }
+    *size = Some((rows, cols));
+    Ok(Board::new(rows, cols, cells))
+}
+
+impl Game {
+    pub fn read(input: &str) -> Result<Game> {
+        let blocks = parse::blocks(input.as_bytes())?;
+        let mut blocks_iter = blocks.iter();
+
+        // 0,5,8,9... etc
+        let first = match blocks_iter.next() {
+            Some(block) => block,
+            None => {
+                return Err(ParseError::missing("", "a line of called numbers")
+                    .at_line(1)
+                    .into())
+            }
+        };
+        let line = &first.lines[0];
+        let called: Vec<BingoNumber> =
+            parse::comma_separated(line).map_err(|e| e.at_line(first.start_line))?;
+
+        // The boards start after a blank line
+        if let Some(next) = first.lines.get(1) {
+            return Err(ParseError::new(next, next, "a blank line")
+                .at_line(first.start_line + 1)
+                .into());
+        }
+
+        let mut size = None;
+        let boards = blocks_iter
+            .map(|block| read_board(block, &mut size))
+            .collect::<Result<Vec<Board>, ParseError>>()?;
+
+        return Ok(Game { called, boards });
+    }
+
+    // Rows x columns of every board, None if there aren't any
+    pub fn board_size(&self) -> Option<Size> {
+        self.boards.first().map(|b| (b.rows(), b.cols()))
+    }
+}
+
+// The puzzle's input format, which Game::read reads back
+impl fmt::Display for Game {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let called: Vec<String> = self.called.iter().map(|n| n.to_string()).collect();
+        writeln!(f, "{}", called.join(","))?;
+        // Numbers are right aligned, to the widest number on any board
+        let width = self
+            .boards
+            .iter()
+            .flat_map(|board| board.cells())
+            .map(|(_, n)| n.to_string().len())
+            .max()
+            .unwrap_or(0);
+        for board in &self.boards {
+            writeln!(f)?;
+            for row in 0..board.rows() {
+                let numbers: Vec<String> = (0..board.cols())
+                    .map(|col| format!("{:>width$}", board.get((row, col)), width = width))
+                    .collect();
+                writeln!(f, "{}", numbers.join(" "))?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,270 @@
+// Makes up games in the puzzle's input format, for fuzzing and for trying out variants. The
+// same seed always gives the same game. It can also break a game in one of a few ways, and
+// say which line the parser should complain about.
+use crate::{BingoNumber, Board, Game};
+use std::{collections::HashSet, fmt, ops::RangeInclusive};
+
+// SplitMix64, small and good enough to shuffle with
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    // 0..n, n has to be more than 0
+    pub fn below(&mut self, n: u64) -> u64 {
+        self.next_u64() % n
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = self.below(i as u64 + 1) as usize;
+            items.swap(i, j);
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Duplicates {
+    // No number twice on a board, or twice in the calls, like the puzzle
+    Never,
+    // Boards and calls can both repeat numbers
+    Allowed,
+}
+
+pub struct Generator {
+    pub seed: u64,
+    pub boards: usize,
+    pub rows: usize,
+    pub cols: usize,
+    pub numbers: RangeInclusive<BingoNumber>,
+    // How many numbers get called, every number in the range if None. That's only allowed for
+    // ranges of up to MAX_CALL_ALL numbers.
+    pub calls: Option<usize>,
+    pub duplicates: Duplicates,
+}
+
+// Calling every number in a bigger range than this has to be asked for with calls
+pub const MAX_CALL_ALL: u64 = 1 << 20;
+
+impl Default for Generator {
+    // The puzzle's shape: 100 5x5 boards and every number from 0 to 99 called once
+    fn default() -> Self {
+        Generator {
+            seed: 0,
+            boards: 100,
+            rows: 5,
+            cols: 5,
+            numbers: 0..=99,
+            calls: None,
+            duplicates: Duplicates::Never,
+        }
+    }
+}
+
+// Ways to break a game
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Defect {
+    ShortRow,
+    LongRow,
+    MissingRow,
+    ExtraRow,
+    NotANumber,
+    NoBlankLine,
+}
+
+impl Defect {
+    pub const ALL: [Defect; 6] = [
+        Defect::ShortRow,
+        Defect::LongRow,
+        Defect::MissingRow,
+        Defect::ExtraRow,
+        Defect::NotANumber,
+        Defect::NoBlankLine,
+    ];
+
+    pub fn from_name(name: &str) -> Option<Defect> {
+        match name {
+            "short-row" => Some(Defect::ShortRow),
+            "long-row" => Some(Defect::LongRow),
+            "missing-row" => Some(Defect::MissingRow),
+            "extra-row" => Some(Defect::ExtraRow),
+            "not-a-number" => Some(Defect::NotANumber),
+            "no-blank-line" => Some(Defect::NoBlankLine),
+            _ => None,
+        }
+    }
+}
+
+// Settings that can't make a game, eg boards bigger than the range of numbers allows
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SettingsError(pub String);
+
+impl fmt::Display for SettingsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+// A broken input, and the 1-based line the parser should report
+pub struct Malformed {
+    pub input: String,
+    pub line: usize,
+}
+
+impl Generator {
+    fn range_size(&self) -> u64 {
+        (*self.numbers.end() as i64 - *self.numbers.start() as i64 + 1).max(0) as u64
+    }
+
+    fn pick(&self, rng: &mut Rng) -> BingoNumber {
+        (*self.numbers.start() as i64 + rng.below(self.range_size()) as i64) as BingoNumber
+    }
+
+    // count numbers from the range, all different if duplicates aren't allowed
+    fn sample(&self, rng: &mut Rng, count: usize) -> Vec<BingoNumber> {
+        if self.duplicates == Duplicates::Allowed {
+            return (0..count).map(|_| self.pick(rng)).collect();
+        }
+        // Shuffle the whole range if most of it is needed, otherwise draw until there's enough
+        if count as u64 * 2 > self.range_size() {
+            let mut all: Vec<BingoNumber> = self.numbers.clone().collect();
+            rng.shuffle(&mut all);
+            all.truncate(count);
+            return all;
+        }
+        let mut seen = HashSet::new();
+        let mut numbers = Vec::with_capacity(count);
+        while numbers.len() < count {
+            let n = self.pick(rng);
+            if seen.insert(n) {
+                numbers.push(n);
+            }
+        }
+        return numbers;
+    }
+
+    pub fn game(&self) -> Result<Game, SettingsError> {
+        let size = self.range_size();
+        if size == 0 {
+            return Err(SettingsError("the range of numbers is empty".to_string()));
+        }
+        let calls = match self.calls {
+            Some(calls) => calls,
+            None if size <= MAX_CALL_ALL => size as usize,
+            None => {
+                return Err(SettingsError(format!(
+                    "calling all {} numbers in the range is too many, say how many to call",
+                    size
+                )))
+            }
+        };
+        // A board without cells would read back as no board at all
+        if self.rows == 0 || self.cols == 0 {
+            return Err(SettingsError(format!(
+                "a {}x{} board has no cells",
+                self.rows, self.cols
+            )));
+        }
+        // An empty line of calls would read back as no line at all
+        if calls == 0 {
+            return Err(SettingsError("a game needs at least one call".to_string()));
+        }
+        if self.duplicates == Duplicates::Never {
+            if (self.rows * self.cols) as u64 > size {
+                return Err(SettingsError(format!(
+                    "can't fill a {}x{} board from {} different numbers",
+                    self.rows, self.cols, size
+                )));
+            }
+            if calls as u64 > size {
+                return Err(SettingsError(format!(
+                    "can't call {} different numbers out of {}",
+                    calls, size
+                )));
+            }
+        }
+
+        let mut rng = Rng::new(self.seed);
+        let called = self.sample(&mut rng, calls);
+        let boards = (0..self.boards)
+            .map(|_| {
+                let cells = self.sample(&mut rng, self.rows * self.cols);
+                Board::new(self.rows, self.cols, cells)
+            })
+            .collect();
+        Ok(Game { called, boards })
+    }
+
+    // A game with one defect, put somewhere the parser can only notice it on one line. That
+    // needs at least two boards, the first one sets the size.
+    pub fn malformed(&self, defect: Defect) -> Result<Malformed, SettingsError> {
+        if self.boards < 2 || self.rows < 2 || self.cols < 2 {
+            return Err(SettingsError(
+                "a malformed game needs at least two boards of 2x2 or more".to_string(),
+            ));
+        }
+        let game = self.game()?;
+        let mut rng = Rng::new(self.seed ^ 0xdefec7);
+        let mut lines: Vec<String> = game.to_string().lines().map(str::to_string).collect();
+
+        // 0-based line of a row of a board
+        let board = 1 + rng.below(self.boards as u64 - 1) as usize;
+        let row = rng.below(self.rows as u64) as usize;
+        let first_line = 2 + board * (self.rows + 1);
+        let i = first_line + row;
+
+        let line = match defect {
+            Defect::ShortRow => {
+                let mut numbers: Vec<&str> = lines[i].split_whitespace().collect();
+                numbers.pop();
+                lines[i] = numbers.join(" ");
+                i
+            }
+            Defect::LongRow => {
+                let extra = self.pick(&mut rng);
+                lines[i] = format!("{} {}", lines[i], extra);
+                i
+            }
+            // The parser notices at the board's last line
+            Defect::MissingRow => {
+                lines.remove(i);
+                first_line + self.rows - 2
+            }
+            Defect::ExtraRow => {
+                let last = first_line + self.rows;
+                lines.insert(last, lines[i].clone());
+                last
+            }
+            Defect::NotANumber => {
+                let mut numbers: Vec<String> =
+                    lines[i].split_whitespace().map(str::to_string).collect();
+                let col = rng.below(numbers.len() as u64) as usize;
+                numbers[col] = "x".repeat(numbers[col].len());
+                lines[i] = numbers.join(" ");
+                i
+            }
+            Defect::NoBlankLine => {
+                lines.remove(1);
+                1
+            }
+        };
+        let mut input = lines.join("\n");
+        input.push('\n');
+        Ok(Malformed {
+            input,
+            line: line + 1,
+        })
+    }
+}
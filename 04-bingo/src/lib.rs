@@ -0,0 +1,34 @@
+pub mod analysis;
+pub mod board;
+pub mod engine;
+pub mod game;
+pub mod generator;
+mod pt1;
+mod pt2;
+pub mod rules;
+pub mod timeline;
+
+pub use analysis::{Place, Strength};
+pub use board::Board;
+pub use engine::{Engine, Win};
+pub use game::Game;
+pub use generator::{Defect, Duplicates, Generator, SettingsError};
+pub use pt1::Part1;
+pub use pt2::Part2;
+pub use rules::{
+    Blackout, Cell, Columns, Custom, Diagonals, FourCorners, Pattern, Rows, Rules, WinRule,
+};
+
+pub use timeline::{Timeline, Turn};
+
+use aoc_core::Answer;
+
+pub type BingoNumber = i32;
+
+fn answer(win: &Win) -> Answer {
+    Answer::new(win.score())
+        .with("board", win.board + 1)
+        .with("turn", win.turn)
+        .with("called", win.called)
+        .with("unmarked_sum", win.unmarked_sum)
+}
@@ -0,0 +1,29 @@
+use crate::{answer, Game, Rules, Timeline};
+use aoc_core::{info, Answer, Error, Result, Solver};
+
+pub struct Part1;
+
+impl Solver for Part1 {
+    type Input = Game;
+
+    fn parse(&self, input: &str) -> Result<Game> {
+        Game::read(input)
+    }
+
+    fn solve(&self, game: &Game) -> Result<Answer> {
+        let timeline = Timeline::play(game, &Rules::standard());
+        match timeline.first_winner() {
+            Some(win) => {
+                info!(
+                    "Called {}! We have a winner! \nIt's {:?} \n unmarked sum: {} product: {}",
+                    win.called,
+                    game.boards[win.board],
+                    win.unmarked_sum,
+                    win.score()
+                );
+                Ok(answer(win))
+            }
+            None => Err(Error::NoSolution("No winners!".to_string())),
+        }
+    }
+}
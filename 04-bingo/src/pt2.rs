@@ -0,0 +1,38 @@
+use crate::{answer, Game, Rules, Timeline};
+use aoc_core::{info, Answer, Error, Result, Solver};
+
+pub struct Part2;
+
+impl Solver for Part2 {
+    type Input = Game;
+
+    fn parse(&self, input: &str) -> Result<Game> {
+        Game::read(input)
+    }
+
+    fn solve(&self, game: &Game) -> Result<Answer> {
+        let timeline = Timeline::play(game, &Rules::standard());
+        // The squid needs the board that wins last, so every board has to win
+        let never_won = timeline.never_won();
+        if !never_won.is_empty() {
+            return Err(Error::NoSolution(format!(
+                "{} boards never win, eg board {}",
+                never_won.len(),
+                never_won[0] + 1
+            )));
+        }
+        match timeline.last_winner() {
+            Some(win) => {
+                info!(
+                    "Called {}! We have our last winner! \nIt's {:?} \n unmarked sum: {} product: {}",
+                    win.called,
+                    game.boards[win.board],
+                    win.unmarked_sum,
+                    win.score()
+                );
+                Ok(answer(win))
+            }
+            None => Err(Error::NoSolution("the game has no boards".to_string())),
+        }
+    }
+}
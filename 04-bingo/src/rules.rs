@@ -0,0 +1,221 @@
+// What counts as a win. Every rule boils down to patterns: sets of cells that win once all
+// of them are marked. A board wins as soon as any pattern of any of the rules is complete, so
+// rules combine just by listing them.
+use aoc_core::ParseError;
+
+// (row, column), from the top left
+pub type Cell = (usize, usize);
+
+pub trait WinRule {
+    fn name(&self) -> &str;
+    // The winning patterns on a board of this size, none if the rule doesn't fit it
+    fn patterns(&self, rows: usize, cols: usize) -> Vec<Vec<Cell>>;
+}
+
+// A complete pattern, and the rule it came from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pattern {
+    pub rule: String,
+    pub cells: Vec<Cell>,
+}
+
+pub struct Rows;
+
+impl WinRule for Rows {
+    fn name(&self) -> &str {
+        "row"
+    }
+    fn patterns(&self, rows: usize, cols: usize) -> Vec<Vec<Cell>> {
+        (0..rows)
+            .map(|row| (0..cols).map(|col| (row, col)).collect())
+            .collect()
+    }
+}
+
+pub struct Columns;
+
+impl WinRule for Columns {
+    fn name(&self) -> &str {
+        "column"
+    }
+    fn patterns(&self, rows: usize, cols: usize) -> Vec<Vec<Cell>> {
+        (0..cols)
+            .map(|col| (0..rows).map(|row| (row, col)).collect())
+            .collect()
+    }
+}
+
+// Both corner to corner diagonals. Only square boards have them.
+pub struct Diagonals;
+
+impl WinRule for Diagonals {
+    fn name(&self) -> &str {
+        "diagonal"
+    }
+    fn patterns(&self, rows: usize, cols: usize) -> Vec<Vec<Cell>> {
+        if rows != cols || rows == 0 {
+            return Vec::new();
+        }
+        vec![
+            (0..rows).map(|i| (i, i)).collect(),
+            (0..rows).map(|i| (i, cols - 1 - i)).collect(),
+        ]
+    }
+}
+
+pub struct FourCorners;
+
+impl WinRule for FourCorners {
+    fn name(&self) -> &str {
+        "four corners"
+    }
+    fn patterns(&self, rows: usize, cols: usize) -> Vec<Vec<Cell>> {
+        if rows == 0 || cols == 0 {
+            return Vec::new();
+        }
+        let mut corners = vec![(0, 0), (0, cols - 1), (rows - 1, 0), (rows - 1, cols - 1)];
+        corners.sort();
+        corners.dedup();
+        vec![corners]
+    }
+}
+
+// Every cell on the board
+pub struct Blackout;
+
+impl WinRule for Blackout {
+    fn name(&self) -> &str {
+        "blackout"
+    }
+    fn patterns(&self, rows: usize, cols: usize) -> Vec<Vec<Cell>> {
+        vec![(0..rows)
+            .flat_map(|row| (0..cols).map(move |col| (row, col)))
+            .collect()]
+    }
+}
+
+// Any fixed set of cells, eg a letter or a picture frame. Doesn't apply to boards it doesn't
+// fit on.
+pub struct Custom {
+    name: String,
+    cells: Vec<Cell>,
+}
+
+impl Custom {
+    pub fn new(name: &str, cells: Vec<Cell>) -> Self {
+        Custom {
+            name: name.to_string(),
+            cells,
+        }
+    }
+
+    // Rows separated by '/', 'X' for a cell in the pattern and '.' for one that isn't,
+    // eg "X...X/.X.X./..X../.X.X./X...X"
+    pub fn from_mask(name: &str, mask: &str) -> Result<Self, ParseError> {
+        let mut cells = Vec::new();
+        for (row, line) in mask.split('/').enumerate() {
+            for (i, c) in line.char_indices() {
+                match c {
+                    'X' | 'x' => cells.push((row, line[..i].chars().count())),
+                    '.' => {}
+                    _ => {
+                        let token = &line[i..i + c.len_utf8()];
+                        return Err(ParseError::new(line, token, "'X' or '.'").within(mask, line));
+                    }
+                }
+            }
+        }
+        if cells.is_empty() {
+            return Err(ParseError::new(mask, mask, "at least one 'X'"));
+        }
+        Ok(Custom::new(name, cells))
+    }
+}
+
+impl WinRule for Custom {
+    fn name(&self) -> &str {
+        &self.name
+    }
+    fn patterns(&self, rows: usize, cols: usize) -> Vec<Vec<Cell>> {
+        if self
+            .cells
+            .iter()
+            .any(|&(row, col)| row >= rows || col >= cols)
+        {
+            return Vec::new();
+        }
+        vec![self.cells.clone()]
+    }
+}
+
+// The rules a game is played by
+pub struct Rules {
+    rules: Vec<Box<dyn WinRule>>,
+}
+
+impl Rules {
+    // No rules at all, add some with `with`
+    pub fn new() -> Self {
+        Rules { rules: Vec::new() }
+    }
+
+    // The puzzle's rules: a full row or column, no diagonals
+    pub fn standard() -> Self {
+        Rules::new().with(Rows).with(Columns)
+    }
+
+    pub fn with(mut self, rule: impl WinRule + 'static) -> Self {
+        self.rules.push(Box::new(rule));
+        self
+    }
+
+    // A comma separated list, eg "rows,columns,diagonals" or "corners,pattern:X.X/.X./X.X"
+    pub fn from_spec(spec: &str) -> Result<Self, ParseError> {
+        let mut rules = Rules::new();
+        for token in spec.split(',').map(str::trim) {
+            rules = match token {
+                "rows" => rules.with(Rows),
+                "columns" | "cols" => rules.with(Columns),
+                "diagonals" => rules.with(Diagonals),
+                "corners" => rules.with(FourCorners),
+                "blackout" => rules.with(Blackout),
+                _ => match token.strip_prefix("pattern:") {
+                    Some(mask) => {
+                        let custom =
+                            Custom::from_mask("pattern", mask).map_err(|e| e.within(spec, mask))?;
+                        rules.with(custom)
+                    }
+                    None => {
+                        return Err(ParseError::new(
+                            spec,
+                            token,
+                            "rows, columns, diagonals, corners, blackout or pattern:<mask>",
+                        ))
+                    }
+                },
+            }
+        }
+        Ok(rules)
+    }
+
+    // Every winning pattern on a board of this size
+    pub fn patterns(&self, rows: usize, cols: usize) -> Vec<Pattern> {
+        self.rules
+            .iter()
+            .flat_map(|rule| {
+                rule.patterns(rows, cols)
+                    .into_iter()
+                    .map(move |cells| Pattern {
+                        rule: rule.name().to_string(),
+                        cells,
+                    })
+            })
+            .collect()
+    }
+}
+
+impl Default for Rules {
+    fn default() -> Self {
+        Rules::standard()
+    }
+}
@@ -0,0 +1,88 @@
+// A whole game, played to the last call. Every turn records the boards that won on it, so
+// the order every board won in can be read off afterwards, and the first, last or any other
+// place comes from the same run. Boards that win on the same turn are placed in board order.
+use crate::{BingoNumber, Engine, Game, Pattern, Rules, Win};
+use aoc_core::trace;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Turn {
+    // 1-based
+    pub turn: usize,
+    pub called: BingoNumber,
+    pub wins: Vec<Win>,
+}
+
+pub struct Timeline {
+    turns: Vec<Turn>,
+    patterns: Vec<Pattern>,
+    boards: usize,
+}
+
+impl Timeline {
+    pub fn play(game: &Game, rules: &Rules) -> Self {
+        let mut engine = Engine::new(game, rules);
+        let turns = game
+            .called
+            .iter()
+            .map(|&called| {
+                let wins = engine.call(called);
+                trace!("Called {}! {} boards won.", called, wins.len());
+                Turn {
+                    turn: engine.turn(),
+                    called,
+                    wins,
+                }
+            })
+            .collect();
+        Timeline {
+            turns,
+            patterns: engine.patterns().to_vec(),
+            boards: game.boards.len(),
+        }
+    }
+
+    pub fn turns(&self) -> &[Turn] {
+        &self.turns
+    }
+
+    // The patterns Win::lines refer to
+    pub fn patterns(&self) -> &[Pattern] {
+        &self.patterns
+    }
+
+    // Every win, in the order the boards won
+    pub fn wins(&self) -> impl Iterator<Item = &Win> {
+        self.turns.iter().flat_map(|turn| &turn.wins)
+    }
+
+    // 1-based, so place 1 is the first winner
+    pub fn nth_winner(&self, place: usize) -> Option<&Win> {
+        self.wins().nth(place.checked_sub(1)?)
+    }
+
+    pub fn first_winner(&self) -> Option<&Win> {
+        self.wins().next()
+    }
+
+    // The last board to win, of those that won at all
+    pub fn last_winner(&self) -> Option<&Win> {
+        self.wins().last()
+    }
+
+    // The place board came in, 1-based, and how it won
+    pub fn place_of(&self, board: usize) -> Option<(usize, &Win)> {
+        self.wins()
+            .enumerate()
+            .find(|(_, win)| win.board == board)
+            .map(|(i, win)| (i + 1, win))
+    }
+
+    // Boards that never won, in board order
+    pub fn never_won(&self) -> Vec<usize> {
+        let mut won = vec![false; self.boards];
+        for win in self.wins() {
+            won[win.board] = true;
+        }
+        (0..self.boards).filter(|&b| !won[b]).collect()
+    }
+}
@@ -0,0 +1,90 @@
+use bingo::{
+    analysis::{self, Place},
+    Board, Game, Rules, Timeline,
+};
+use std::collections::HashSet;
+
+fn example() -> Game {
+    let input =
+        std::fs::read_to_string(concat!(env!("CARGO_MANIFEST_DIR"), "/input_simple.txt")).unwrap();
+    Game::read(&input).unwrap()
+}
+
+#[test]
+fn earliest_wins() {
+    let game = example();
+    let patterns = Rules::standard().patterns(5, 5);
+    let called: HashSet<i32> = game.called.iter().copied().collect();
+    for board in &game.boards {
+        assert_eq!(analysis::earliest_win(board, &patterns, &called), Some(5));
+    }
+    // 99 isn't called, so the only row with it can't win
+    let board = Board::new(1, 2, vec![99, 1]);
+    let patterns = Rules::standard().patterns(1, 2);
+    assert_eq!(analysis::earliest_win(&board, &patterns, &called), Some(1));
+    let patterns = Rules::new().with(bingo::Rows).patterns(1, 2);
+    assert_eq!(analysis::earliest_win(&board, &patterns, &called), None);
+}
+
+#[test]
+fn ranking() {
+    let ranked = analysis::rank(&example(), &Rules::standard());
+    let boards: Vec<(usize, Option<usize>)> = ranked.iter().map(|s| (s.board, s.turn)).collect();
+    assert_eq!(boards, [(2, Some(12)), (0, Some(14)), (1, Some(15))]);
+    assert_eq!(ranked[0].earliest, Some(5));
+
+    // Each board is judged by the patterns for its own size
+    let game = Game {
+        called: vec![11, 12, 13, 3, 4],
+        boards: vec![
+            Board::new(2, 2, vec![1, 2, 3, 4]),
+            Board::new(3, 3, (5..=13).collect()),
+        ],
+    };
+    let ranked = analysis::rank(&game, &Rules::standard());
+    let boards: Vec<(usize, Option<usize>)> =
+        ranked.iter().map(|s| (s.board, s.earliest)).collect();
+    assert_eq!(boards, [(1, Some(3)), (0, Some(2))]);
+}
+
+fn place_with(game: &Game, called: Vec<i32>, board: usize) -> (usize, usize) {
+    let mut sorted = called.clone();
+    sorted.sort();
+    let mut original = game.called.clone();
+    original.sort();
+    assert_eq!(sorted, original, "the same calls, reordered");
+
+    let game = Game {
+        called,
+        boards: game.boards.clone(),
+    };
+    let timeline = Timeline::play(&game, &Rules::standard());
+    let (place, _) = timeline.place_of(board).unwrap();
+    (place, timeline.wins().count())
+}
+
+#[test]
+fn any_board_can_come_first_or_last() {
+    let game = example();
+    for board in 0..game.boards.len() {
+        let first = analysis::arrange(&game, &Rules::standard(), board, Place::First).unwrap();
+        assert_eq!(place_with(&game, first, board).0, 1);
+
+        let last = analysis::arrange(&game, &Rules::standard(), board, Place::Last).unwrap();
+        let (place, winners) = place_with(&game, last, board);
+        assert_eq!(place, winners);
+    }
+}
+
+#[test]
+fn impossible_arrangements() {
+    // The second board wins whenever the first does
+    let game = Game {
+        called: vec![1, 2, 3],
+        boards: vec![Board::new(1, 2, vec![1, 2]), Board::new(1, 2, vec![2, 1])],
+    };
+    let rules = Rules::new().with(bingo::Rows);
+    assert!(analysis::arrange(&game, &rules, 0, Place::First).is_none());
+    assert!(analysis::arrange(&game, &rules, 0, Place::Last).is_none());
+    assert!(analysis::arrange(&game, &rules, 5, Place::First).is_none());
+}
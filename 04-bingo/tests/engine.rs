@@ -0,0 +1,88 @@
+use bingo::{Board, Diagonals, Engine, Game, Rules};
+
+fn game(called: &[i32], boards: Vec<Board>) -> Game {
+    Game {
+        called: called.to_vec(),
+        boards,
+    }
+}
+
+#[test]
+fn winners_are_reported_on_the_call_that_completes_a_line() {
+    let example =
+        std::fs::read_to_string(concat!(env!("CARGO_MANIFEST_DIR"), "/input_simple.txt")).unwrap();
+    let game = Game::read(&example).unwrap();
+    let mut engine = Engine::new(&game, &Rules::standard());
+    let mut wins = Vec::new();
+    for &n in &game.called {
+        wins.extend(engine.call(n));
+    }
+    let order: Vec<(usize, usize)> = wins.iter().map(|w| (w.board, w.turn)).collect();
+    assert_eq!(order, [(2, 12), (0, 14), (1, 15)]);
+    assert_eq!(wins[0].score(), 4512);
+    assert_eq!(engine.patterns()[wins[0].lines[0]].rule, "row");
+    assert_eq!(wins[2].score(), 1924);
+    assert_eq!(engine.remaining(), 0);
+}
+
+#[test]
+fn won_boards_stop_taking_marks() {
+    let game = game(&[1, 2, 4, 3], vec![Board::new(2, 2, vec![1, 2, 4, 3])]);
+    let mut engine = Engine::new(&game, &Rules::new().with(Diagonals));
+    assert!(engine.call(1).is_empty());
+    assert!(engine.is_marked(0, (0, 0)));
+    assert!(engine.call(2).is_empty());
+    assert_eq!(engine.call(3)[0].lines, [0]);
+    assert!(engine.has_won(0));
+    assert!(engine.call(4).is_empty());
+    assert!(!engine.is_marked(0, (1, 0)));
+}
+
+#[test]
+fn repeated_numbers() {
+    let boards = vec![
+        Board::new(2, 2, vec![5, 6, 7, 5]),
+        Board::new(2, 2, vec![1, 2, 3, 5]),
+    ];
+    let game = game(&[5, 5, 6], boards);
+    let mut engine = Engine::new(&game, &Rules::standard());
+    assert!(engine.call(5).is_empty());
+    // A second call of the same number marks nothing new
+    assert!(engine.call(5).is_empty());
+    let wins = engine.call(6);
+    assert_eq!(wins.len(), 1);
+    assert_eq!((wins[0].board, wins[0].turn), (0, 3));
+    // Row 0 and column 1 both finish
+    assert_eq!(wins[0].lines.len(), 2);
+    assert_eq!(wins[0].unmarked_sum, 7);
+}
+
+#[test]
+fn large_numbers_dont_overflow() {
+    let max = i32::MAX;
+    let cells = vec![max, max - 1, max - 2, max - 3];
+    let game = game(&[max, max - 1], vec![Board::new(2, 2, cells)]);
+    let mut engine = Engine::new(&game, &Rules::standard());
+    assert!(engine.call(max).is_empty());
+    let win = engine.call(max - 1).remove(0);
+    let unmarked = (max - 2) as i128 + (max - 3) as i128;
+    assert_eq!(win.unmarked_sum, unmarked);
+    assert_eq!(win.score(), unmarked * (max - 1) as i128);
+}
+
+#[test]
+fn boards_of_different_sizes() {
+    let small = Board::new(2, 2, vec![1, 2, 3, 4]);
+    let big = Board::new(3, 3, (5..=13).collect());
+    let game = game(&[11, 12, 13, 3, 4], vec![small, big]);
+    let mut engine = Engine::new(&game, &Rules::standard());
+    let wins: Vec<(usize, usize)> = game
+        .called
+        .iter()
+        .flat_map(|&n| engine.call(n))
+        .map(|w| (w.board, w.turn))
+        .collect();
+    assert_eq!(wins, [(1, 3), (0, 5)]);
+    assert!(engine.is_marked(1, (2, 2)));
+    assert!(engine.is_marked(0, (1, 1)));
+}
@@ -0,0 +1,48 @@
+use aoc_core::{Error, Solver, Value};
+use bingo::{Game, Part1, Part2};
+
+fn parse_error(input: &str) -> aoc_core::ParseError {
+    match Game::read(input) {
+        Err(Error::Parse(e)) => e,
+        other => panic!("expected a parse error, got {:?}", other),
+    }
+}
+
+#[test]
+fn board_size_comes_from_the_input() {
+    let input = "1,2,3,4\n\n1 2 3\n4 5 6\n\n7 8 9\n1 2 3\n";
+    let game = Game::read(input).unwrap();
+    assert_eq!(game.board_size(), Some((2, 3)));
+    assert_eq!(game.boards.len(), 2);
+    assert_eq!(game.boards[1].get((1, 2)), 3);
+
+    // The first board's top row, with 4 + 5 + 6 unmarked
+    assert_eq!(Part1.solve(&game).unwrap().value, Value::Int(45));
+}
+
+#[test]
+fn every_board_is_the_same_size() {
+    let e = parse_error("1,2,3\n\n1 2 3\n4 5 6\n\n7 8 9\n1 2\n");
+    assert_eq!(e.line, Some(7));
+    assert_eq!(e.expected, "3 numbers per row");
+
+    let e = parse_error("1,2,3\n\n1 2 3\n4 5 6\n\n7 8 9\n1 2 3\n4 5 6\n");
+    assert_eq!(e.line, Some(8));
+    assert_eq!(e.expected, "2 rows per board");
+}
+
+#[test]
+fn too_few_calls_is_found_when_solving() {
+    // Too few for a row, but other rules could still have a winner
+    let game = Game::read("1,2\n\n1 2 3\n4 5 6\n7 8 9\n").unwrap();
+    assert!(matches!(Part1.solve(&game), Err(Error::NoSolution(_))));
+}
+
+#[test]
+fn no_boards_no_last_winner() {
+    let game = Game::read("1,2,3\n").unwrap();
+    match Part2.solve(&game) {
+        Err(Error::NoSolution(why)) => assert_eq!(why, "the game has no boards"),
+        other => panic!("expected no solution, got {:?}", other),
+    }
+}
@@ -0,0 +1,118 @@
+use aoc_core::Error;
+use bingo::{Defect, Duplicates, Game, Generator};
+use std::collections::HashSet;
+
+#[test]
+fn same_seed_same_game() {
+    let generator = Generator {
+        seed: 42,
+        ..Generator::default()
+    };
+    let game = generator.game().unwrap();
+    assert_eq!(game, generator.game().unwrap());
+    assert_ne!(game, Generator::default().game().unwrap());
+    assert_eq!(game.boards.len(), 100);
+    assert_eq!(game.board_size(), Some((5, 5)));
+    // Every number once, in some order
+    let called: HashSet<i32> = game.called.iter().copied().collect();
+    assert_eq!((game.called.len(), called.len()), (100, 100));
+}
+
+#[test]
+fn games_read_back() {
+    let generator = Generator {
+        boards: 4,
+        rows: 3,
+        cols: 7,
+        numbers: -50..=1000,
+        calls: Some(60),
+        ..Generator::default()
+    };
+    let game = generator.game().unwrap();
+    assert_eq!(Game::read(&game.to_string()).unwrap(), game);
+    for board in &game.boards {
+        let numbers: HashSet<i32> = board.cells().map(|(_, n)| n).collect();
+        assert_eq!(numbers.len(), 21);
+        assert!(numbers.iter().all(|n| (-50..=1000).contains(n)));
+    }
+
+    // Fewer calls than a row needs still makes a game, just one nobody wins
+    let generator = Generator {
+        boards: 2,
+        calls: Some(3),
+        ..Generator::default()
+    };
+    let game = generator.game().unwrap();
+    assert_eq!(Game::read(&game.to_string()).unwrap(), game);
+    let no_calls = Generator {
+        calls: Some(0),
+        ..generator
+    };
+    assert!(no_calls.game().is_err());
+    let no_rows = Generator {
+        rows: 0,
+        boards: 3,
+        ..Generator::default()
+    };
+    assert_eq!(
+        no_rows.game().unwrap_err().to_string(),
+        "a 0x5 board has no cells"
+    );
+
+    // Calling a whole huge range has to be asked for
+    let huge = Generator {
+        boards: 2,
+        numbers: -1_000_000_000..=1_000_000_000,
+        ..Generator::default()
+    };
+    assert!(huge.game().is_err());
+    let game = Generator {
+        calls: Some(50),
+        ..huge
+    }
+    .game()
+    .unwrap();
+    assert_eq!(Game::read(&game.to_string()).unwrap(), game);
+}
+
+#[test]
+fn duplicate_policy() {
+    let generator = Generator {
+        numbers: 1..=3,
+        calls: Some(10),
+        boards: 1,
+        rows: 2,
+        cols: 2,
+        duplicates: Duplicates::Allowed,
+        ..Generator::default()
+    };
+    let game = generator.game().unwrap();
+    assert_eq!(game.called.len(), 10);
+
+    let never = Generator {
+        duplicates: Duplicates::Never,
+        ..generator
+    };
+    assert_eq!(
+        never.game().unwrap_err().to_string(),
+        "can't fill a 2x2 board from 3 different numbers"
+    );
+}
+
+#[test]
+fn malformed_games_fail_on_the_right_line() {
+    for seed in 0..5 {
+        let generator = Generator {
+            seed,
+            boards: 6,
+            ..Generator::default()
+        };
+        for defect in Defect::ALL {
+            let malformed = generator.malformed(defect).unwrap();
+            match Game::read(&malformed.input) {
+                Err(Error::Parse(e)) => assert_eq!(e.line, Some(malformed.line), "{:?}", defect),
+                other => panic!("{:?} parsed: {:?}", defect, other.err()),
+            }
+        }
+    }
+}
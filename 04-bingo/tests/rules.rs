@@ -0,0 +1,67 @@
+use bingo::{Blackout, Board, Custom, Diagonals, Engine, FourCorners, Game, Rules, WinRule};
+
+fn board() -> Board {
+    Board::new(3, 3, (1..=9).collect())
+}
+
+// The rule the board wins by once every number is called, if it wins
+fn winning_rule(rules: &Rules, numbers: &[i32]) -> Option<String> {
+    let game = Game {
+        called: numbers.to_vec(),
+        boards: vec![board()],
+    };
+    let mut engine = Engine::new(&game, rules);
+    let win = numbers.iter().flat_map(|&n| engine.call(n)).next()?;
+    Some(engine.patterns()[win.lines[0]].rule.clone())
+}
+
+#[test]
+fn standard_rules_are_rows_and_columns() {
+    let rules = Rules::standard();
+    assert_eq!(winning_rule(&rules, &[4, 5, 6]).as_deref(), Some("row"));
+    assert_eq!(winning_rule(&rules, &[2, 5, 8]).as_deref(), Some("column"));
+    assert_eq!(winning_rule(&rules, &[1, 5, 9]), None);
+}
+
+#[test]
+fn extra_rules() {
+    let rules = Rules::new().with(Diagonals).with(FourCorners);
+    assert_eq!(
+        winning_rule(&rules, &[3, 5, 7]).as_deref(),
+        Some("diagonal")
+    );
+    assert_eq!(
+        winning_rule(&rules, &[1, 3, 7, 9]).as_deref(),
+        Some("four corners")
+    );
+    let rules = Rules::new().with(Blackout);
+    assert_eq!(winning_rule(&rules, &[1, 2, 3, 4, 5, 6, 7, 8]), None);
+    assert!(winning_rule(&rules, &[1, 2, 3, 4, 5, 6, 7, 8, 9]).is_some());
+    // Rectangular boards don't have diagonals
+    assert!(Diagonals.patterns(2, 3).is_empty());
+}
+
+#[test]
+fn custom_patterns() {
+    let plus = Custom::from_mask("plus", ".X./XXX/.X.").unwrap();
+    let rules = Rules::new().with(plus);
+    assert_eq!(
+        winning_rule(&rules, &[2, 4, 5, 6, 8]).as_deref(),
+        Some("plus")
+    );
+    // Doesn't fit on a 2x2 board
+    assert!(rules.patterns(2, 2).is_empty());
+
+    let e = Custom::from_mask("bad", "X./.Y").err().unwrap();
+    assert_eq!((e.token.as_str(), e.column), ("Y", 5));
+}
+
+#[test]
+fn rule_specs() {
+    let rules = Rules::from_spec("rows, diagonals,pattern:X.X").unwrap();
+    assert_eq!(rules.patterns(3, 3).len(), 6);
+    let e = Rules::from_spec("rows,stars").err().unwrap();
+    assert_eq!(e.token, "stars");
+    let e = Rules::from_spec("rows,pattern:X?").err().unwrap();
+    assert_eq!(e.column, 15);
+}
@@ -0,0 +1,45 @@
+use bingo::{Board, Game, Rules, Timeline};
+
+fn example() -> Game {
+    let input =
+        std::fs::read_to_string(concat!(env!("CARGO_MANIFEST_DIR"), "/input_simple.txt")).unwrap();
+    Game::read(&input).unwrap()
+}
+
+#[test]
+fn every_place_from_one_run() {
+    let timeline = Timeline::play(&example(), &Rules::standard());
+    assert_eq!(timeline.turns().len(), 27);
+    assert_eq!(timeline.first_winner().unwrap().score(), 4512);
+    assert_eq!(timeline.last_winner().unwrap().score(), 1924);
+    assert_eq!(timeline.nth_winner(2).unwrap().board, 0);
+    assert!(timeline.nth_winner(0).is_none());
+    assert!(timeline.nth_winner(4).is_none());
+
+    let (place, win) = timeline.place_of(1).unwrap();
+    assert_eq!((place, win.turn), (3, 15));
+    assert!(timeline.never_won().is_empty());
+
+    let turn = &timeline.turns()[11];
+    assert_eq!((turn.turn, turn.called), (12, 24));
+    assert_eq!(turn.wins.len(), 1);
+    let line = &timeline.patterns()[turn.wins[0].lines[0]];
+    assert_eq!((line.rule.as_str(), line.cells[0]), ("row", (0, 0)));
+}
+
+#[test]
+fn ties_and_boards_that_never_win() {
+    let game = Game {
+        called: vec![1, 2],
+        boards: vec![
+            Board::new(1, 2, vec![2, 1]),
+            Board::new(1, 2, vec![3, 4]),
+            Board::new(1, 2, vec![1, 2]),
+        ],
+    };
+    let timeline = Timeline::play(&game, &Rules::standard());
+    let order: Vec<usize> = timeline.wins().map(|w| w.board).collect();
+    assert_eq!(order, [0, 2]);
+    assert_eq!(timeline.last_winner().unwrap().board, 2);
+    assert_eq!(timeline.never_won(), [1]);
+}
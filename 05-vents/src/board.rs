@@ -0,0 +1,237 @@
+// Where the vents are, and how many lines cross each point. Small, crowded boards keep every
+// cell in a Vec. Boards that would be mostly empty, or too big to allocate at all (coordinates
+// in the millions, or far apart), only keep the cells that have been marked, in a HashMap.
+// Either way the coordinates are the input's own, negative ones included.
+use aoc_core::{info, trace, Answer};
+use std::{collections::HashMap, fmt};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Cell {
+    Empty,
+    Marked,
+    Overlap,
+}
+
+impl Cell {
+    fn mark(self) -> Cell {
+        match self {
+            Cell::Empty => Cell::Marked,
+            Cell::Marked => Cell::Overlap,
+            Cell::Overlap => Cell::Overlap,
+        }
+    }
+}
+
+// The smallest rectangle holding every point, inclusive
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Extent {
+    pub min_x: i32,
+    pub min_y: i32,
+    pub max_x: i32,
+    pub max_y: i32,
+}
+
+impl Extent {
+    // None if there aren't any points
+    pub fn of(points: impl IntoIterator<Item = (i32, i32)>) -> Option<Extent> {
+        points.into_iter().fold(None, |extent, (x, y)| {
+            Some(match extent {
+                None => Extent {
+                    min_x: x,
+                    min_y: y,
+                    max_x: x,
+                    max_y: y,
+                },
+                Some(e) => Extent {
+                    min_x: e.min_x.min(x),
+                    min_y: e.min_y.min(y),
+                    max_x: e.max_x.max(x),
+                    max_y: e.max_y.max(y),
+                },
+            })
+        })
+    }
+
+    pub fn width(&self) -> u64 {
+        (self.max_x as i64 - self.min_x as i64 + 1) as u64
+    }
+
+    pub fn height(&self) -> u64 {
+        (self.max_y as i64 - self.min_y as i64 + 1) as u64
+    }
+
+    pub fn area(&self) -> u64 {
+        self.width().saturating_mul(self.height())
+    }
+
+    pub fn contains(&self, x: i32, y: i32) -> bool {
+        (self.min_x..=self.max_x).contains(&x) && (self.min_y..=self.max_y).contains(&y)
+    }
+
+    // Where x,y is in a dense board's cells
+    fn index(&self, x: i32, y: i32) -> usize {
+        (x as i64 - self.min_x as i64) as usize
+            + self.width() as usize * (y as i64 - self.min_y as i64) as usize
+    }
+}
+
+// 64MB of cells at most
+const MAX_DENSE_CELLS: u64 = 1 << 26;
+// A dense board is faster until fewer than 1 in this many cells get marked
+const MIN_DENSITY: u64 = 16;
+
+enum Storage {
+    // Every cell in the extent, a row at a time
+    Dense { extent: Extent, cells: Vec<Cell> },
+    // Only the cells that have been marked
+    Sparse { cells: HashMap<(i32, i32), Cell> },
+}
+
+pub struct Board {
+    // Where the lines are, if the board was made for them
+    extent: Option<Extent>,
+    storage: Storage,
+}
+
+impl Board {
+    // Points outside extent can't be marked
+    pub fn dense(extent: Extent) -> Self {
+        let cells = vec![Cell::Empty; extent.area() as usize];
+        Board {
+            extent: Some(extent),
+            storage: Storage::Dense { extent, cells },
+        }
+    }
+
+    pub fn sparse() -> Self {
+        Board {
+            extent: None,
+            storage: Storage::Sparse {
+                cells: HashMap::new(),
+            },
+        }
+    }
+
+    // Picks dense or sparse from how much of extent the expected number of marks would cover
+    pub fn new(extent: Extent, marks: u64) -> Self {
+        let area = extent.area();
+        if area <= MAX_DENSE_CELLS && area <= marks.saturating_mul(MIN_DENSITY) {
+            Board::dense(extent)
+        } else {
+            Board::sparse()
+        }
+    }
+
+    // A board for lines from start to end, sized to hold them all. Only the lines draw keeps
+    // will be marked, so they decide whether it's dense or sparse.
+    pub fn for_lines(
+        lines: impl IntoIterator<Item = ((i32, i32), (i32, i32))>,
+        draw: impl Fn((i32, i32), (i32, i32)) -> bool,
+    ) -> Self {
+        let lines: Vec<_> = lines.into_iter().collect();
+        for line in &lines {
+            trace!("line: {:?}", line);
+        }
+        let extent = Extent::of(lines.iter().flat_map(|&(start, end)| [start, end]));
+        // Points on the drawn lines, counting overlaps twice
+        let marks: u64 = lines
+            .iter()
+            .filter(|&&(start, end)| draw(start, end))
+            .map(|&((x1, y1), (x2, y2))| {
+                let dx = (x2 as i64 - x1 as i64).unsigned_abs();
+                let dy = (y2 as i64 - y1 as i64).unsigned_abs();
+                dx.max(dy) + 1
+            })
+            .sum();
+        let mut board = match extent {
+            Some(extent) => Board::new(extent, marks),
+            None => Board::sparse(),
+        };
+        board.extent = extent;
+        let (width, height) = board.size();
+        info!(
+            "Decided board is {}x{}, {}",
+            width,
+            height,
+            if board.is_sparse() { "sparse" } else { "dense" }
+        );
+        board
+    }
+
+    // Width and height of the lines' extent, 0x0 if it isn't known
+    pub fn size(&self) -> (u64, u64) {
+        self.extent.map_or((0, 0), |e| (e.width(), e.height()))
+    }
+
+    // The overlaps once every line is drawn, and how the board was kept
+    pub fn answer(&self, lines: usize) -> Answer {
+        let (width, height) = self.size();
+        Answer::new(self.count_intersections())
+            .with("lines", lines)
+            .with("width", width)
+            .with("height", height)
+            .with("sparse", self.is_sparse())
+    }
+
+    pub fn is_sparse(&self) -> bool {
+        matches!(self.storage, Storage::Sparse { .. })
+    }
+
+    pub fn mark(&mut self, x: i32, y: i32) {
+        match &mut self.storage {
+            Storage::Dense { extent, cells } => {
+                assert!(extent.contains(x, y), "{},{} is off the board", x, y);
+                let idx = extent.index(x, y);
+                cells[idx] = cells[idx].mark();
+            }
+            Storage::Sparse { cells } => {
+                let cell = cells.entry((x, y)).or_insert(Cell::Empty);
+                *cell = cell.mark();
+            }
+        }
+    }
+
+    pub fn get(&self, x: i32, y: i32) -> Cell {
+        match &self.storage {
+            Storage::Dense { extent, cells } => {
+                if !extent.contains(x, y) {
+                    return Cell::Empty;
+                }
+                cells[extent.index(x, y)]
+            }
+            Storage::Sparse { cells } => cells.get(&(x, y)).copied().unwrap_or(Cell::Empty),
+        }
+    }
+
+    pub fn count_intersections(&self) -> usize {
+        let is_overlap = |c: &&Cell| **c == Cell::Overlap;
+        match &self.storage {
+            Storage::Dense { cells, .. } => cells.iter().filter(is_overlap).count(),
+            Storage::Sparse { cells } => cells.values().filter(is_overlap).count(),
+        }
+    }
+}
+
+impl fmt::Debug for Board {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.storage {
+            Storage::Dense { extent, cells } => {
+                cells.chunks(extent.width() as usize).try_for_each(|row| {
+                    writeln!(f)?;
+                    row.iter().try_for_each(|cell| {
+                        write!(
+                            f,
+                            "{}",
+                            match cell {
+                                Cell::Empty => " ",
+                                Cell::Marked => ".",
+                                Cell::Overlap => "X",
+                            }
+                        )
+                    })
+                })
+            }
+            Storage::Sparse { cells } => write!(f, "sparse, {} cells marked", cells.len()),
+        }
+    }
+}
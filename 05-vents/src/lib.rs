@@ -0,0 +1,7 @@
+pub mod board;
+mod pt1;
+mod pt2;
+
+pub use board::{Board, Cell, Extent};
+pub use pt1::Part1;
+pub use pt2::Part2;
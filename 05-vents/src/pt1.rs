@@ -0,0 +1,97 @@
+use crate::Board;
+use aoc_core::{parse, trace, Answer, ParseError, Result, Solver};
+use std::{fmt::Debug, mem::swap, str::FromStr};
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct Line {
+    start: Point,
+    end: Point,
+}
+
+impl Line {
+    fn endpoints(&self) -> ((i32, i32), (i32, i32)) {
+        ((self.start.x, self.start.y), (self.end.x, self.end.y))
+    }
+
+    fn normalize(&mut self) {
+        // TODO: handle non-horizontal lines
+        let abnormal = self.start.x > self.end.x || self.start.y > self.end.y;
+        if abnormal {
+            swap(&mut self.start, &mut self.end);
+        }
+    }
+}
+
+impl FromStr for Line {
+    type Err = ParseError;
+    // "x1,y1 -> x2,y2"
+    fn from_str(line_str: &str) -> Result<Self, ParseError> {
+        // "x,y", with errors pointing into the whole line
+        let parse_point = |pt: &str| match pt.split_once(',') {
+            Some((x, y)) => Ok(Point {
+                x: parse::field(line_str, x)?,
+                y: parse::field(line_str, y)?,
+            }),
+            None => Err(ParseError::new(line_str, pt, "'x,y'")),
+        };
+        // (x1,y1), (x2,y2)
+        match line_str.split_once(" -> ") {
+            Some((start, end)) => Ok(Line {
+                start: parse_point(start)?,
+                end: parse_point(end)?,
+            }),
+            None => Err(ParseError::new(line_str, line_str, "'x1,y1 -> x2,y2'")),
+        }
+    }
+}
+
+// Marks every point of line
+fn write(board: &mut Board, line: &Line) {
+    // Assume line is either vertical or horizontal
+    let vertical = line.start.x == line.end.x;
+    let horizontal = line.start.y == line.end.y;
+    if vertical {
+        let x = line.start.x;
+        for y in line.start.y..=line.end.y {
+            board.mark(x, y);
+        }
+    } else if horizontal {
+        let y = line.start.y;
+        for x in line.start.x..=line.end.x {
+            board.mark(x, y);
+        }
+    } else {
+        trace!("Skippling line {:?}", line);
+        // todo!("Write line {:?}", line);
+    }
+}
+
+pub struct Part1;
+
+impl Solver for Part1 {
+    type Input = Vec<Line>;
+
+    fn parse(&self, input: &str) -> Result<Vec<Line>> {
+        parse::map_lines(input.as_bytes(), |line| line.parse())
+    }
+
+    fn solve(&self, lines: &Vec<Line>) -> Result<Answer> {
+        let mut board =
+            Board::for_lines(lines.iter().map(Line::endpoints), |(x1, y1), (x2, y2)| {
+                x1 == x2 || y1 == y2
+            });
+        for line in lines {
+            let mut line = line.clone();
+            line.normalize();
+            write(&mut board, &line);
+        }
+        trace!("Board {:?} ", &board);
+        Ok(board.answer(lines.len()))
+    }
+}
@@ -0,0 +1,128 @@
+use crate::Board;
+use aoc_core::{parse, trace, Answer, ParseError, Result, Solver};
+use std::{fmt::Debug, ops::Add, str::FromStr};
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+impl Add for Point {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Point {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub struct Line {
+    start: Point,
+    end: Point,
+}
+
+impl Line {
+    fn endpoints(&self) -> ((i32, i32), (i32, i32)) {
+        ((self.start.x, self.start.y), (self.end.x, self.end.y))
+    }
+}
+
+impl FromStr for Line {
+    type Err = ParseError;
+    // "x1,y1 -> x2,y2"
+    fn from_str(line_str: &str) -> Result<Self, ParseError> {
+        // "x,y", with errors pointing into the whole line
+        let parse_point = |pt: &str| match pt.split_once(',') {
+            Some((x, y)) => Ok(Point {
+                x: parse::field(line_str, x)?,
+                y: parse::field(line_str, y)?,
+            }),
+            None => Err(ParseError::new(line_str, pt, "'x,y'")),
+        };
+        // (x1,y1), (x2,y2)
+        let line = match line_str.split_once(" -> ") {
+            Some((start, end)) => Line {
+                start: parse_point(start)?,
+                end: parse_point(end)?,
+            },
+            None => return Err(ParseError::new(line_str, line_str, "'x1,y1 -> x2,y2'")),
+        };
+        // Any other slope would step right past its end when it's drawn
+        let dx = (line.end.x as i64 - line.start.x as i64).abs();
+        let dy = (line.end.y as i64 - line.start.y as i64).abs();
+        if dx != 0 && dy != 0 && dx != dy {
+            return Err(ParseError::new(
+                line_str,
+                line_str.trim(),
+                "a horizontal, vertical or 45° line",
+            ));
+        }
+        Ok(line)
+    }
+}
+
+impl Add for Line {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Line {
+            start: self.start + rhs.start,
+            end: self.end + rhs.end,
+        }
+    }
+}
+
+// Marks every point of line
+fn write(board: &mut Board, line: &Line) {
+    // Assume line is either vertical or horizontal
+    // In i64, far apart points can overflow i32
+    let dx = clamp(line.end.x as i64 - line.start.x as i64, -1, 1) as i32;
+    let dy = clamp(line.end.y as i64 - line.start.y as i64, -1, 1) as i32;
+    let d = Point { x: dx, y: dy };
+    let mut cur = line.start;
+    trace!(
+        "line: start: {} {} => {} {}",
+        line.start.x,
+        line.start.y,
+        dx,
+        dy
+    );
+    loop {
+        board.mark(cur.x, cur.y);
+        if cur == line.end {
+            break;
+        }
+        cur = cur + d;
+    }
+}
+
+fn clamp<T: PartialOrd>(x: T, min: T, max: T) -> T {
+    if x < min {
+        return min;
+    }
+    if x > max {
+        return max;
+    }
+    return x;
+}
+
+pub struct Part2;
+
+impl Solver for Part2 {
+    type Input = Vec<Line>;
+
+    fn parse(&self, input: &str) -> Result<Vec<Line>> {
+        parse::map_lines(input.as_bytes(), |line| line.parse())
+    }
+
+    fn solve(&self, lines: &Vec<Line>) -> Result<Answer> {
+        let mut board = Board::for_lines(lines.iter().map(Line::endpoints), |_, _| true);
+        for line in lines {
+            write(&mut board, line);
+        }
+        trace!("Board {:?} ", &board);
+        Ok(board.answer(lines.len()))
+    }
+}
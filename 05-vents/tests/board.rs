@@ -0,0 +1,93 @@
+use aoc_core::{Error, Solver, Value};
+use vents::{Board, Cell, Extent, Part1, Part2};
+
+#[test]
+fn extent_and_backend_choice() {
+    let extent = Extent::of([(-3, 2), (5, -1), (0, 0)]).unwrap();
+    assert_eq!((extent.width(), extent.height()), (9, 4));
+    assert!(Extent::of([]).is_none());
+
+    assert!(!Board::new(extent, 10).is_sparse());
+    // Mostly empty
+    assert!(Board::new(extent, 2).is_sparse());
+    // Far too big to allocate
+    let huge = Extent::of([(i32::MIN, i32::MIN), (i32::MAX, i32::MAX)]).unwrap();
+    assert_eq!(huge.area(), u64::MAX);
+    assert!(Board::new(huge, u64::MAX).is_sparse());
+}
+
+#[test]
+fn boards_for_lines() {
+    // The diagonal isn't drawn, but the board still covers it
+    let lines = [((0, 0), (0, 3)), ((0, 0), (9, 9))];
+    let board = Board::for_lines(lines, |(x1, _), (x2, _)| x1 == x2);
+    assert_eq!(board.size(), (10, 10));
+    assert!(board.is_sparse());
+    let answer = board.answer(lines.len());
+    assert_eq!(answer.value, Value::Int(0));
+    assert!(answer.details.contains(&("width", Value::Int(10))));
+
+    assert!(!Board::for_lines(lines, |_, _| true).is_sparse());
+    assert_eq!(Board::for_lines([], |_, _| true).size(), (0, 0));
+}
+
+#[test]
+fn both_backends_count_the_same() {
+    let extent = Extent::of([(-2, -2), (2, 2)]).unwrap();
+    for mut board in [Board::dense(extent), Board::sparse()] {
+        for (x, y) in [(-2, -2), (0, 1), (-2, -2), (0, 1), (0, 1), (2, 2)] {
+            board.mark(x, y);
+        }
+        assert_eq!(board.count_intersections(), 2);
+        assert_eq!(board.get(2, 2), Cell::Marked);
+        assert_eq!(board.get(1, 1), Cell::Empty);
+        assert_eq!(board.get(100, 100), Cell::Empty);
+    }
+}
+
+#[test]
+fn negative_and_huge_coordinates() {
+    let input = "-5,-5 -> -5,5\n-10,0 -> 10,0\n2000000000,-2000000000 -> 2000000000,-1999999990\n\
+                 2000000000,-1999999995 -> 1999999990,-1999999995\n-8,-8 -> 8,8\n";
+    let lines = Part1.parse(input).unwrap();
+    let answer = Part1.solve(&lines).unwrap();
+    assert_eq!(answer.value, Value::Int(2));
+    assert!(answer.details.contains(&("sparse", Value::Bool(true))));
+    // The diagonal adds -5,-5 and 0,0
+    assert_eq!(
+        Part2.solve(&Part2.parse(input).unwrap()).unwrap().value,
+        Value::Int(4)
+    );
+}
+
+#[test]
+fn other_slopes_are_rejected() {
+    // Small enough for a dense board, then far enough out for a sparse one
+    for (input, line) in [
+        ("0,0 -> 3,3\n0,0 -> 3,1\n", 2),
+        ("0,0 -> 3,1\n5000000,5000000 -> 5000000,5000001\n", 1),
+    ] {
+        match Part2.parse(input) {
+            Err(Error::Parse(e)) => {
+                assert_eq!(e.line, Some(line));
+                assert_eq!(e.expected, "a horizontal, vertical or 45° line");
+            }
+            other => panic!("expected a parse error, got {:?}", other),
+        }
+    }
+    // 45° both ways still draw on either backend
+    for (input, sparse) in [
+        ("0,0 -> 3,3\n3,0 -> 0,3\n", false),
+        (
+            "0,0 -> 3,3\n3,0 -> 0,3\n5000000,5000000 -> 5000000,5000001\n",
+            true,
+        ),
+    ] {
+        let answer = Part2.solve(&Part2.parse(input).unwrap()).unwrap();
+        assert_eq!(answer.value, Value::Int(0));
+        assert!(answer.details.contains(&("sparse", Value::Bool(sparse))));
+    }
+    // Part 1 skips anything that isn't straight
+    let lines = Part1.parse("0,0 -> 3,1\n").unwrap();
+    assert_eq!(Part1.solve(&lines).unwrap().value, Value::Int(0));
+}
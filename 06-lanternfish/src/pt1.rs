@@ -0,0 +1,48 @@
+use aoc_core::{debug, parse, Answer, Result, Solver};
+
+#[derive(Clone)]
+pub struct Fish(u32);
+const BABY_FISH: Fish = Fish(8);
+const ADULT_FISH: Fish = Fish(6);
+
+impl Fish {
+    // Update state and optionally create a new fish
+    fn next(&self) -> (Self, Option<Self>) {
+        match self {
+            Fish(0) => (ADULT_FISH, Some(BABY_FISH)),
+            Fish(i) => (Fish(i - 1), None),
+        }
+    }
+}
+
+fn read_input(input: &str) -> Result<Vec<Fish>> {
+    let ages: Vec<u32> = parse::single_line(input.as_bytes(), parse::comma_separated)?;
+    Ok(ages.into_iter().map(Fish).collect())
+}
+
+pub struct Part1;
+
+impl Solver for Part1 {
+    type Input = Vec<Fish>;
+
+    fn parse(&self, input: &str) -> Result<Vec<Fish>> {
+        read_input(input)
+    }
+
+    fn solve(&self, fish: &Vec<Fish>) -> Result<Answer> {
+        let mut fish = fish.clone();
+        // One fish per element, so this only works for the 80 days of part 1
+        for day in 0..80 {
+            // Only need to run update for old fishes, not new
+            for i in 0..fish.len() {
+                let (fish_updated, new_fish) = fish[i].next();
+                if let Some(new_fish) = new_fish {
+                    fish.push(new_fish);
+                }
+                fish[i] = fish_updated;
+            }
+            debug!("Fish count after {}: {}", day + 1, fish.len());
+        }
+        return Ok(Answer::new(fish.len()).with("days", 80));
+    }
+}
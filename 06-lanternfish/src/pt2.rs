@@ -0,0 +1,67 @@
+use aoc_core::{debug, parse, Answer, ParseError, Result, Solver};
+
+// Just an array of the count of fish of each age [0 ... 8]  inclusive
+#[derive(Clone, Copy)]
+pub struct FishState([u64; 9]);
+
+impl FishState {
+    fn next(&mut self) {
+        let bebes = self.0[0];
+        // Decrement fish of each age
+        for i in 1..self.0.len() {
+            self.0[i - 1] = self.0[i];
+        }
+        // Spawn bebes
+        self.0[8] = bebes;
+        // Previous ready to spawn now adults
+        self.0[6] += bebes;
+    }
+
+    fn count(&self) -> u64 {
+        self.0.iter().sum()
+    }
+}
+
+fn read_input(input: &str) -> Result<FishState> {
+    let fish: Vec<usize> = parse::single_line(input.as_bytes(), |line| {
+        line.trim()
+            .split(',')
+            .map(|age| match parse::field(line, age)? {
+                age @ 0..=8 => Ok(age),
+                _ => Err(ParseError::new(line, age, "an age from 0 to 8")),
+            })
+            .collect()
+    })?;
+
+    let fish_state = fish.iter().fold(FishState([0; 9]), |mut state, &fish| {
+        state.0[fish] += 1;
+        state
+    });
+    Ok(fish_state)
+}
+
+pub struct Part2;
+
+impl Solver for Part2 {
+    type Input = FishState;
+
+    fn parse(&self, input: &str) -> Result<FishState> {
+        read_input(input)
+    }
+
+    fn solve(&self, fish_state: &FishState) -> Result<Answer> {
+        let mut fish_state = *fish_state;
+        debug!("Fish initial state: {:?}", &fish_state.0);
+        for day in 0..256 {
+            // Only need to run update for old fishes, not new
+            fish_state.next();
+            debug!(
+                "Day {} Count: {} State: {:?}",
+                day + 1,
+                fish_state.count(),
+                &fish_state.0
+            );
+        }
+        return Ok(Answer::new(fish_state.count()).with("days", 256));
+    }
+}
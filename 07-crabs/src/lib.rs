@@ -0,0 +1,5 @@
+mod pt1;
+mod pt2;
+
+pub use pt1::Part1;
+pub use pt2::Part2;
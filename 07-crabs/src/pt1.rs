@@ -0,0 +1,43 @@
+use aoc_core::{info, parse, Answer, Error, Result, Solver};
+
+fn fuel_cost(crab_locations: &[i32], location: i32) -> i32 {
+    let mut cost = 0;
+    for crab in crab_locations {
+        cost += (crab - location).abs();
+    }
+    cost
+}
+
+// Returns (x, x_cost), None if there aren't any crabs
+// Could do a fancier algorithm, but this is good enough to solve this size of problem
+fn minimize_fuel(crabs: &[i32]) -> Option<(i32, i32)> {
+    let (min_x, max_x) = crabs.iter().fold((i32::MAX, i32::MIN), |(min, max), crab| {
+        (min.min(*crab), max.max(*crab))
+    });
+    info!("Checking {} to {}", min_x, max_x);
+    let mut best: Option<(i32, i32)> = None;
+    for x in min_x..=max_x {
+        let cost = fuel_cost(crabs, x);
+        if best.is_none_or(|(_, best_cost)| cost < best_cost) {
+            best = Some((x, cost));
+        }
+        // println!("x={} => {}", x, cost);
+    }
+    best
+}
+
+pub struct Part1;
+
+impl Solver for Part1 {
+    type Input = Vec<i32>;
+
+    fn parse(&self, input: &str) -> Result<Vec<i32>> {
+        parse::single_line(input.as_bytes(), parse::comma_separated)
+    }
+
+    fn solve(&self, crabs: &Vec<i32>) -> Result<Answer> {
+        let best = minimize_fuel(crabs)
+            .ok_or_else(|| Error::NoSolution("there aren't any crabs".to_string()))?;
+        Ok(Answer::new(best.1).with("best_x", best.0))
+    }
+}
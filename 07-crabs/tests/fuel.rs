@@ -0,0 +1,15 @@
+use aoc_core::{Solver, Value};
+use crabs::{Part1, Part2};
+
+#[test]
+fn the_furthest_crab_is_a_candidate() {
+    let crabs = Part1.parse("5\n").unwrap();
+    assert_eq!(Part1.solve(&crabs).unwrap().value, Value::Int(0));
+    assert_eq!(Part2.solve(&crabs).unwrap().value, Value::Int(0));
+
+    // Best spot is the highest position
+    let crabs = Part1.parse("1,9,9\n").unwrap();
+    let answer = Part1.solve(&crabs).unwrap();
+    assert_eq!(answer.value, Value::Int(8));
+    assert_eq!(answer.details, [("best_x", Value::Int(9))]);
+}
@@ -0,0 +1,48 @@
+use aoc_core::{parse, Answer, Result, Solver};
+
+fn digit_lut(segment_count: u8) -> Option<u8> {
+    match segment_count {
+        2 => Some(1),
+        3 => Some(7),
+        4 => Some(4),
+        7 => Some(8),
+        _ => None,
+    }
+}
+
+fn read_digits(second_part: &str) -> Vec<u8> {
+    second_part
+        .split_whitespace()
+        .map(|run| run.len() as u8)
+        .collect()
+}
+
+fn read_input(input: &str) -> Result<Vec<Vec<u8>>> {
+    // Just read the length of each digit list
+    parse::map_lines(input.as_bytes(), |l| {
+        let (_first_part, second_part) = parse::split_pipe(l)?;
+        // Split each line into digits, then just return the segment count in each one
+        Ok(read_digits(second_part))
+    })
+}
+
+pub struct Part1;
+
+impl Solver for Part1 {
+    type Input = Vec<Vec<u8>>;
+
+    fn parse(&self, input: &str) -> Result<Vec<Vec<u8>>> {
+        read_input(input)
+    }
+
+    fn solve(&self, segment_counts: &Vec<Vec<u8>>) -> Result<Answer> {
+        let count_1_4_7_8 = segment_counts.iter().flatten().fold(0, |count, &segment| {
+            count
+                + match digit_lut(segment) {
+                    Some(_) => 1,
+                    None => 0,
+                }
+        });
+        Ok(Answer::new(count_1_4_7_8).with("entries", segment_counts.len()))
+    }
+}
@@ -0,0 +1,54 @@
+use aoc_core::{debug, parse, Answer, Result, Solver};
+use std::cmp::min;
+
+fn make_low_point_iter(input: &Vec<Vec<u8>>) -> impl Iterator<Item = (usize, usize, u8)> + '_ {
+    // For each point in the grid, check if it's the lowest among its neighbours
+    let rows = input.len();
+    let cols = input[0].len();
+    (0..rows)
+        .flat_map(move |r| (0..cols).map(move |c| (r, c, input[r][c])))
+        .filter(move |(r, c, v)| {
+            // Check if it's the lowest among its up/down neighbours
+            for y in r.saturating_sub(1)..=min(r + 1, rows - 1) {
+                if y != *r && input[y][*c] <= *v {
+                    return false;
+                }
+            }
+            // Check if it's the lowest among its left/right neighbours
+            for x in c.saturating_sub(1)..=min(c + 1, cols - 1) {
+                if x != *c && input[*r][x] <= *v {
+                    return false;
+                }
+            }
+            true
+        })
+}
+
+pub struct Part1;
+
+impl Solver for Part1 {
+    type Input = Vec<Vec<u8>>;
+
+    fn parse(&self, input: &str) -> Result<Vec<Vec<u8>>> {
+        parse::digit_grid(input.as_bytes())
+    }
+
+    fn solve(&self, input: &Vec<Vec<u8>>) -> Result<Answer> {
+        // Calculate risk level
+        let low_points = make_low_point_iter(input);
+        debug!(
+            "Low points: {:?}",
+            make_low_point_iter(input)
+                .filter(|(_, c, _)| *c == 99)
+                .collect::<Vec<_>>()
+        );
+        // The risk level of a low point is 1 plus its height
+        // Return the sum of total risk level
+        let mut count = 0;
+        let risk: u32 = low_points
+            .inspect(|_| count += 1)
+            .map(|(_, _, v)| v as u32 + 1)
+            .sum();
+        Ok(Answer::new(risk).with("low_points", count))
+    }
+}
@@ -1,28 +1,12 @@
+use aoc_core::{
+    debug, info,
+    log::{self, Level},
+    parse, trace, Answer, Error, Result, Solver,
+};
 use std::cmp::min;
-use std::fmt::Debug;
-use std::io::{self, BufRead};
 
 type Heightmap = Vec<Vec<u8>>;
 
-// Read a 2d grid of numbers
-fn read_input() -> io::Result<Heightmap> {
-    let stdin = io::stdin();
-
-    stdin
-        .lock()
-        .lines()
-        .map(|l| {
-            // If read line ok
-            l.map(|l| {
-                // Transform each char into a number
-                l.chars()
-                    .map(|c| c.to_digit(10).unwrap() as u8)
-                    .collect::<Vec<u8>>()
-            })
-        })
-        .collect()
-}
-
 fn make_low_point_iter(input: &Heightmap) -> impl Iterator<Item = (usize, usize, u8)> + '_ {
     // For each point in the grid, check if it's the lowest among its neighbours
     let rows = input.len();
@@ -143,49 +127,59 @@ fn assign_points_to_basins(b: &BasinAssign, input: &Heightmap) -> BasinAssign {
     }
 }
 
-fn check_input(input: &Heightmap) -> bool {
-    // Make sure input has same number of columns in each row
-    let cols = input[0].len();
-    input.iter().all(|row| row.len() == cols)
-}
+pub struct Part2;
 
-fn main() {
-    let input = read_input().unwrap();
-    if !check_input(&input) {
-        panic!("Input is not a valid grid");
-    }
-    // Initialize basin assignments
-    let mut b = init_basins_assign(&input);
-    // Assign points to basins
-    while b.unassigned > 0 {
-        println!("Unassigned: {}", b.unassigned);
-        println!("{}", b);
-        b = assign_points_to_basins(&b, &input);
+impl Solver for Part2 {
+    type Input = Heightmap;
+
+    fn parse(&self, input: &str) -> Result<Heightmap> {
+        parse::digit_grid(input.as_bytes())
     }
 
-    for (r, row) in input.iter().enumerate() {
-        for (c, height) in row.iter().enumerate() {
-            print!(
-                "{:1}",
-                if b.basins[r][c].is_none() {
-                    ' ' as char
-                } else {
-                    (input[r][c] + 48) as char
-                }
-            );
+    fn solve(&self, input: &Heightmap) -> Result<Answer> {
+        // Initialize basin assignments
+        let mut b = init_basins_assign(input);
+        // Assign points to basins
+        while b.unassigned > 0 {
+            debug!("Unassigned: {}", b.unassigned);
+            trace!("{}", b);
+            b = assign_points_to_basins(&b, input);
         }
-        println!();
-    }
-    // Print top 3 basins
-    let mut basin_counts = b.basin_counts.clone();
-    basin_counts.sort();
-    basin_counts.reverse();
-    println!("Top 3 basins:");
-    for i in 0..3 {
-        println!("Basin {}: {}", i, basin_counts[i]);
+
+        // Final basin map, with the 9s blanked out
+        if log::enabled(Level::Trace) {
+            for (r, row) in input.iter().enumerate() {
+                let line: String = row
+                    .iter()
+                    .enumerate()
+                    .map(|(c, height)| {
+                        if b.basins[r][c].is_none() {
+                            ' '
+                        } else {
+                            (height + 48) as char
+                        }
+                    })
+                    .collect();
+                trace!("{}", line);
+            }
+        }
+        // Print top 3 basins
+        let mut basin_counts = b.basin_counts.clone();
+        basin_counts.sort();
+        basin_counts.reverse();
+        if basin_counts.len() < 3 {
+            return Err(Error::NoSolution(format!(
+                "only {} basins, the answer needs 3",
+                basin_counts.len()
+            )));
+        }
+        info!("Top 3 basins:");
+        for i in 0..3 {
+            info!("Basin {}: {}", i, basin_counts[i]);
+        }
+        let product = basin_counts[0] * basin_counts[1] * basin_counts[2];
+        Ok(Answer::new(product)
+            .with("basins", basin_counts.len())
+            .with("largest", basin_counts[0]))
     }
-    println!(
-        "Product: {}",
-        basin_counts[0] * basin_counts[1] * basin_counts[2]
-    );
 }
@@ -0,0 +1,10 @@
+use aoc_core::{Error, Solver};
+use smoke::Part2;
+
+#[test]
+fn fewer_than_three_basins() {
+    for input in ["5\n", "19\n91\n"] {
+        let map = Part2.parse(input).unwrap();
+        assert!(matches!(Part2.solve(&map), Err(Error::NoSolution(_))));
+    }
+}
@@ -0,0 +1,43 @@
+use std::fmt;
+
+// An iterator had the wrong number of items for the array it was collected into
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LengthError {
+    pub expected: usize,
+    pub actual: usize,
+}
+
+impl fmt::Display for LengthError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "expected {} items, got {}", self.expected, self.actual)
+    }
+}
+
+impl std::error::Error for LengthError {}
+
+// Collects exactly N items into an array, eg the numbers in a row of a grid. Items are moved
+// straight into the array, nothing is allocated. If there are too many the rest are counted,
+// so the error says how many there were.
+pub trait TryCollectArray: Iterator + Sized {
+    fn try_collect_array<const N: usize>(mut self) -> Result<[Self::Item; N], LengthError> {
+        let items: [Option<Self::Item>; N] = std::array::from_fn(|_| self.next());
+        let taken = items.iter().filter(|item| item.is_some()).count();
+        if taken < N {
+            return Err(LengthError {
+                expected: N,
+                actual: taken,
+            });
+        }
+        let extra = self.count();
+        if extra > 0 {
+            return Err(LengthError {
+                expected: N,
+                actual: N + extra,
+            });
+        }
+        // All N are there, checked above
+        Ok(items.map(|item| item.unwrap()))
+    }
+}
+
+impl<I: Iterator> TryCollectArray for I {}
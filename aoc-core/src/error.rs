@@ -0,0 +1,140 @@
+use std::{fmt, io};
+
+// Something in the input didn't match what the parser expected. Points at the offending
+// token, so malformed input can be fixed without guessing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    // 1-based, None until the line reader fills it in
+    pub line: Option<usize>,
+    // 1-based, in chars
+    pub column: usize,
+    // What was found, empty for end of line / input
+    pub token: String,
+    // What should have been there, eg "i32" or "'x1,y1 -> x2,y2'"
+    pub expected: String,
+    // The text the column refers to
+    pub source: String,
+}
+
+// Byte offset of inner within outer, if inner is a slice of it
+fn offset_of(outer: &str, inner: &str) -> Option<usize> {
+    let outer_start = outer.as_ptr() as usize;
+    let inner_start = inner.as_ptr() as usize;
+    if inner_start >= outer_start && inner_start + inner.len() <= outer_start + outer.len() {
+        Some(inner_start - outer_start)
+    } else {
+        None
+    }
+}
+
+impl ParseError {
+    // token should be a slice of source, that's how the column is worked out
+    pub fn new(source: &str, token: &str, expected: impl Into<String>) -> Self {
+        let column = match offset_of(source, token) {
+            Some(offset) => source[..offset].chars().count() + 1,
+            None => 1,
+        };
+        ParseError {
+            line: None,
+            column,
+            token: token.to_string(),
+            expected: expected.into(),
+            source: source.to_string(),
+        }
+    }
+
+    // Error for something missing at the end of source
+    pub fn missing(source: &str, expected: impl Into<String>) -> Self {
+        Self::new(source, &source[source.len()..], expected)
+    }
+
+    // Re-anchor an error from parsing inner, a slice of outer, so the column is relative to outer
+    pub fn within(mut self, outer: &str, inner: &str) -> Self {
+        if let Some(offset) = offset_of(outer, inner) {
+            self.column += outer[..offset].chars().count();
+            self.source = outer.to_string();
+        }
+        self
+    }
+
+    pub fn at_line(mut self, line: usize) -> Self {
+        self.line = Some(line);
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let found = if self.token.is_empty() {
+            "nothing".to_string()
+        } else {
+            format!("'{}'", self.token)
+        };
+        let gutter = match self.line {
+            Some(line) => {
+                write!(f, "line {}, ", line)?;
+                line.to_string()
+            }
+            None => String::new(),
+        };
+        writeln!(
+            f,
+            "column {}: expected {}, found {}",
+            self.column, self.expected, found
+        )?;
+        //   3 | 0,9 -> x,9
+        //     |        ^
+        writeln!(f, "{} | {}", gutter, self.source)?;
+        write!(
+            f,
+            "{} | {}{}",
+            " ".repeat(gutter.len()),
+            " ".repeat(self.column - 1),
+            "^".repeat(self.token.chars().count().max(1))
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[derive(Debug)]
+pub enum Error {
+    Io(io::Error),
+    Parse(ParseError),
+    // The input was fine, but there's no answer in it (eg nobody wins at bingo)
+    NoSolution(String),
+    // A number got too big for the type it's kept in. source is what was being worked out,
+    // eg the command on that line.
+    Overflow { line: Option<usize>, source: String },
+}
+
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(e) => write!(f, "{}", e),
+            Error::Parse(e) => write!(f, "{}", e),
+            Error::NoSolution(reason) => write!(f, "no solution: {}", reason),
+            Error::Overflow {
+                line: Some(line),
+                source,
+            } => write!(f, "line {}: overflow in '{}'", line, source),
+            Error::Overflow { line: None, source } => write!(f, "overflow in '{}'", source),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(e: ParseError) -> Self {
+        Error::Parse(e)
+    }
+}
@@ -0,0 +1,153 @@
+pub mod array;
+pub mod error;
+pub mod log;
+pub mod parse;
+
+pub use array::{LengthError, TryCollectArray};
+pub use error::{Error, ParseError, Result};
+
+use std::{
+    fmt::{self, Display},
+    time::{Duration, Instant},
+};
+
+// A number, flag or piece of text in an answer. Kept typed so reports can print numbers as
+// numbers rather than strings.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Int(i128),
+    Float(f64),
+    Bool(bool),
+    Text(String),
+}
+
+macro_rules! int_value {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Value {
+            fn from(n: $t) -> Self {
+                Value::Int(n as i128)
+            }
+        })*
+    };
+}
+
+int_value!(i8, i16, i32, i64, i128, u8, u16, u32, u64, usize, isize);
+
+// Past i128 there's only the digits left
+impl From<u128> for Value {
+    fn from(n: u128) -> Self {
+        match i128::try_from(n) {
+            Ok(n) => Value::Int(n),
+            Err(_) => Value::Text(n.to_string()),
+        }
+    }
+}
+
+impl From<f64> for Value {
+    fn from(n: f64) -> Self {
+        Value::Float(n)
+    }
+}
+
+impl From<bool> for Value {
+    fn from(b: bool) -> Self {
+        Value::Bool(b)
+    }
+}
+
+impl From<String> for Value {
+    fn from(s: String) -> Self {
+        Value::Text(s)
+    }
+}
+
+impl From<&str> for Value {
+    fn from(s: &str) -> Self {
+        Value::Text(s.to_string())
+    }
+}
+
+impl<T: Into<Value> + Clone> From<&T> for Value {
+    fn from(v: &T) -> Self {
+        v.clone().into()
+    }
+}
+
+impl Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Int(n) => write!(f, "{}", n),
+            Value::Float(n) => write!(f, "{}", n),
+            Value::Bool(b) => write!(f, "{}", b),
+            Value::Text(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+// What a solver found: the answer itself, plus any named values worked out along the way
+// (eg gamma and epsilon), so they can be reported without scraping log output.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Answer {
+    pub value: Value,
+    pub details: Vec<(&'static str, Value)>,
+}
+
+impl Answer {
+    pub fn new(value: impl Into<Value>) -> Self {
+        Answer {
+            value: value.into(),
+            details: Vec::new(),
+        }
+    }
+
+    pub fn with(mut self, name: &'static str, value: impl Into<Value>) -> Self {
+        self.details.push((name, value.into()));
+        self
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+// One part of one day's puzzle. Parsing is kept separate from solving so the runner can
+// feed the same parsed input to a solver more than once.
+pub trait Solver {
+    type Input;
+
+    fn parse(&self, input: &str) -> Result<Self::Input>;
+    fn solve(&self, input: &Self::Input) -> Result<Answer>;
+}
+
+// The answer from one run, and how long each phase took
+pub struct Run {
+    pub answer: Answer,
+    pub parse_time: Duration,
+    pub solve_time: Duration,
+}
+
+// Solver has an associated type, so it can't be boxed directly. This erases it so that
+// every day can live in the same registry.
+pub trait Puzzle {
+    fn run(&self, input: &str) -> Result<Run>;
+}
+
+impl<S: Solver> Puzzle for S {
+    fn run(&self, input: &str) -> Result<Run> {
+        let start = Instant::now();
+        let parsed = self.parse(input)?;
+        let parse_time = start.elapsed();
+
+        let start = Instant::now();
+        let answer = self.solve(&parsed)?;
+        let solve_time = start.elapsed();
+
+        Ok(Run {
+            answer,
+            parse_time,
+            solve_time,
+        })
+    }
+}
@@ -0,0 +1,132 @@
+// Input readers shared by all the days. Anything line based takes a BufRead, so it works
+// the same on stdin, a file or a &str (via as_bytes()).
+// Errors come back as ParseErrors with the line number filled in.
+use crate::{error::ParseError, Result};
+use std::{any::type_name, io::BufRead, str::FromStr};
+
+// Parse token, a slice of source, as a T. On failure, points at the token.
+pub fn field<T: FromStr>(source: &str, token: &str) -> Result<T, ParseError> {
+    token
+        .parse()
+        .map_err(|_| ParseError::new(source, token, type_name::<T>()))
+}
+
+// Every non-blank line, run through f
+pub fn map_lines<R: BufRead, T>(
+    reader: R,
+    f: impl FnMut(&str) -> Result<T, ParseError>,
+) -> Result<Vec<T>> {
+    let numbered = numbered_lines(reader, f)?;
+    Ok(numbered.into_iter().map(|(_, result)| result).collect())
+}
+
+// Like map_lines, but keeps the 1-based line number of each result, for errors found later on
+pub fn numbered_lines<R: BufRead, T>(
+    reader: R,
+    mut f: impl FnMut(&str) -> Result<T, ParseError>,
+) -> Result<Vec<(usize, T)>> {
+    let mut results = Vec::new();
+    for (i, line) in reader.lines().enumerate() {
+        let line = line?;
+        if !line.trim().is_empty() {
+            results.push((i + 1, f(&line).map_err(|e| e.at_line(i + 1))?));
+        }
+    }
+    Ok(results)
+}
+
+// For inputs that are all on one line, eg a comma separated list
+pub fn single_line<R: BufRead, T>(
+    reader: R,
+    f: impl FnMut(&str) -> Result<T, ParseError>,
+) -> Result<T> {
+    let mut results = numbered_lines(reader, f)?.into_iter();
+    match (results.next(), results.next()) {
+        (Some((_, result)), None) => Ok(result),
+        (None, _) => Err(ParseError::missing("", "a line of input").at_line(1).into()),
+        (Some(_), Some((line, _))) => Err(ParseError::missing("", "a single line of input")
+            .at_line(line)
+            .into()),
+    }
+}
+
+// One T per line, eg a column of depths
+pub fn lines<R: BufRead, T: FromStr>(reader: R) -> Result<Vec<T>> {
+    map_lines(reader, |line| field(line, line.trim()))
+}
+
+// "3,4,3,1,2"
+pub fn comma_separated<T: FromStr>(line: &str) -> Result<Vec<T>, ParseError> {
+    line.trim()
+        .split(',')
+        .map(|token| field(line, token))
+        .collect()
+}
+
+// Rows of single digits, eg a heightmap. Every row has to be the same length, and there has
+// to be at least one.
+pub fn digit_grid<R: BufRead>(reader: R) -> Result<Vec<Vec<u8>>> {
+    let mut width = None;
+    let grid = map_lines(reader, |line| {
+        let row = line
+            .char_indices()
+            .map(|(i, c)| match c.to_digit(10) {
+                Some(d) => Ok(d as u8),
+                None => Err(ParseError::new(line, &line[i..i + c.len_utf8()], "a digit")),
+            })
+            .collect::<Result<Vec<u8>, ParseError>>()?;
+        match width {
+            None => width = Some(row.len()),
+            Some(width) if width != row.len() => {
+                return Err(ParseError::new(
+                    line,
+                    line,
+                    format!("a row of {} digits", width),
+                ))
+            }
+            _ => {}
+        }
+        Ok(row)
+    })?;
+    if grid.is_empty() {
+        return Err(ParseError::missing("", "a row of digits").at_line(1).into());
+    }
+    Ok(grid)
+}
+
+// A group of lines, separated from the next by a blank line
+pub struct Block {
+    // 1-based line number of the first line in the block
+    pub start_line: usize,
+    pub lines: Vec<String>,
+}
+
+// Groups of lines separated by blank lines, eg bingo boards
+pub fn blocks<R: BufRead>(reader: R) -> Result<Vec<Block>> {
+    let mut blocks = Vec::new();
+    let mut current: Option<Block> = None;
+    for (i, line) in reader.lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            blocks.extend(current.take());
+        } else {
+            current
+                .get_or_insert_with(|| Block {
+                    start_line: i + 1,
+                    lines: Vec::new(),
+                })
+                .lines
+                .push(line);
+        }
+    }
+    blocks.extend(current);
+    Ok(blocks)
+}
+
+// "a b c | d e" -> ("a b c", "d e")
+pub fn split_pipe(line: &str) -> Result<(&str, &str), ParseError> {
+    match line.split_once('|') {
+        Some((a, b)) => Ok((a.trim(), b.trim())),
+        None => Err(ParseError::missing(line, "'|'")),
+    }
+}
@@ -0,0 +1,35 @@
+use aoc_core::{LengthError, TryCollectArray};
+
+#[test]
+fn exact_length() {
+    let row: [i32; 3] = "1 2 3"
+        .split(' ')
+        .map(|n| n.parse().unwrap())
+        .try_collect_array()
+        .unwrap();
+    assert_eq!(row, [1, 2, 3]);
+    let empty: [u8; 0] = std::iter::empty().try_collect_array().unwrap();
+    assert_eq!(empty, []);
+}
+
+#[test]
+fn wrong_length_says_how_many() {
+    let short = (0..3).try_collect_array::<5>();
+    assert_eq!(
+        short,
+        Err(LengthError {
+            expected: 5,
+            actual: 3
+        })
+    );
+    let long = (0..8).try_collect_array::<5>().unwrap_err();
+    assert_eq!(long.actual, 8);
+    assert_eq!(long.to_string(), "expected 5 items, got 8");
+}
+
+#[test]
+fn moves_items_that_arent_copy() {
+    let words = ["a", "bc"].map(String::from);
+    let moved: [String; 2] = words.into_iter().try_collect_array().unwrap();
+    assert_eq!(moved, ["a", "bc"]);
+}
@@ -0,0 +1,30 @@
+use aoc_core::{parse, Error};
+
+#[test]
+fn digit_grid_needs_a_row() {
+    assert_eq!(
+        parse::digit_grid("12\n34\n".as_bytes()).unwrap(),
+        [[1, 2], [3, 4]]
+    );
+    match parse::digit_grid("\n".as_bytes()) {
+        Err(Error::Parse(e)) => {
+            assert_eq!(e.line, Some(1));
+            assert_eq!(e.expected, "a row of digits");
+        }
+        other => panic!("expected a parse error, got {:?}", other),
+    }
+}
+
+#[test]
+fn single_line_reports_the_extra_line() {
+    let input = "1,2,3\n\n\n4,5\n";
+    match parse::single_line(input.as_bytes(), parse::comma_separated::<i32>) {
+        Err(Error::Parse(e)) => {
+            assert_eq!(e.line, Some(4));
+            assert_eq!(e.expected, "a single line of input");
+        }
+        other => panic!("expected a parse error, got {:?}", other),
+    }
+    let line = parse::single_line("\n7,8\n".as_bytes(), parse::comma_separated::<i32>);
+    assert_eq!(line.unwrap(), [7, 8]);
+}
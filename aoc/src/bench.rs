@@ -0,0 +1,131 @@
+// Timing for `aoc bench`: every solver is run a number of times on its input.txt, and parse
+// and solve are timed separately. Results can be saved as a baseline and compared later.
+use crate::registry::Entry;
+use aoc_core::Result;
+use serde_json::{json, Value};
+use std::time::Duration;
+
+// Summary of a set of timings, in milliseconds
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub mean: f64,
+    pub median: f64,
+    pub stddev: f64,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Stats {
+        let mut ms: Vec<f64> = samples.iter().map(|d| d.as_secs_f64() * 1000.0).collect();
+        if ms.is_empty() {
+            return Stats {
+                mean: 0.0,
+                median: 0.0,
+                stddev: 0.0,
+            };
+        }
+        ms.sort_by(|a, b| a.total_cmp(b));
+        let n = ms.len() as f64;
+        let mean = ms.iter().sum::<f64>() / n;
+        let median = if ms.len().is_multiple_of(2) {
+            (ms[ms.len() / 2 - 1] + ms[ms.len() / 2]) / 2.0
+        } else {
+            ms[ms.len() / 2]
+        };
+        let variance = ms.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>() / n;
+        Stats {
+            mean,
+            median,
+            stddev: variance.sqrt(),
+        }
+    }
+}
+
+// Timings for one part of one day
+#[derive(Debug, Clone, PartialEq)]
+pub struct Measurement {
+    pub day: u8,
+    pub part: u8,
+    pub iterations: usize,
+    pub parse: Stats,
+    pub solve: Stats,
+}
+
+pub fn measure(entry: &Entry, input: &str, iterations: usize) -> Result<Measurement> {
+    let mut parse_times = Vec::with_capacity(iterations);
+    let mut solve_times = Vec::with_capacity(iterations);
+    for _ in 0..iterations {
+        let run = entry.solver.run(input)?;
+        parse_times.push(run.parse_time);
+        solve_times.push(run.solve_time);
+    }
+    Ok(Measurement {
+        day: entry.day,
+        part: entry.part,
+        iterations,
+        parse: Stats::from_samples(&parse_times),
+        solve: Stats::from_samples(&solve_times),
+    })
+}
+
+fn stats_json(stats: &Stats) -> Value {
+    json!({
+        "mean_ms": stats.mean,
+        "median_ms": stats.median,
+        "stddev_ms": stats.stddev,
+    })
+}
+
+fn stats_from_json(value: &Value) -> Option<Stats> {
+    Some(Stats {
+        mean: value.get("mean_ms")?.as_f64()?,
+        median: value.get("median_ms")?.as_f64()?,
+        stddev: value.get("stddev_ms")?.as_f64()?,
+    })
+}
+
+// A list of measurements, the format baseline files are saved in
+pub fn to_json(measurements: &[Measurement]) -> Value {
+    measurements
+        .iter()
+        .map(|m| {
+            json!({
+                "day": m.day,
+                "part": m.part,
+                "iterations": m.iterations,
+                "parse": stats_json(&m.parse),
+                "solve": stats_json(&m.solve),
+            })
+        })
+        .collect()
+}
+
+// The whole file has to be measurements, the error says which entry isn't
+pub fn from_json(value: &Value) -> Result<Vec<Measurement>, String> {
+    let list = value.as_array().ok_or("expected a list of measurements")?;
+    list.iter()
+        .enumerate()
+        .map(|(i, m)| {
+            measurement_from_json(m)
+                .ok_or_else(|| format!("entry {} isn't a measurement: {}", i + 1, m))
+        })
+        .collect()
+}
+
+fn measurement_from_json(m: &Value) -> Option<Measurement> {
+    Some(Measurement {
+        day: m.get("day")?.as_u64()?.try_into().ok()?,
+        part: m.get("part")?.as_u64()?.try_into().ok()?,
+        iterations: m.get("iterations")?.as_u64()? as usize,
+        parse: stats_from_json(m.get("parse")?)?,
+        solve: stats_from_json(m.get("solve")?)?,
+    })
+}
+
+// Percentage change in median from the baseline, eg -50.0 is twice as fast
+pub fn change(baseline: &Stats, current: &Stats) -> Option<f64> {
+    if baseline.median > 0.0 {
+        Some((current.median - baseline.median) / baseline.median * 100.0)
+    } else {
+        None
+    }
+}
@@ -0,0 +1,608 @@
+use aoc::{
+    bench::{self, Measurement, Stats},
+    registry::{self, Entry},
+    report::{self, Format},
+};
+use aoc_core::{
+    log::{self, Level},
+    parse, Error,
+};
+use binary_diagnostics::Radix;
+use bingo::{Defect, Duplicates, Generator};
+use dive::{Aim, Naive, Number, Severity, Step, Submarine, Trajectory};
+use num_bigint::BigInt;
+use sonar_sweep::{
+    filter::{self, Filter},
+    Outliers, Stream,
+};
+use std::{
+    env, fs,
+    io::{self, BufRead, Read},
+    process,
+    str::FromStr,
+};
+
+const USAGE: &str = "usage:
+    aoc run [--day N] [--part N] [--input PATH] [--format text|json] [-v|-vv|-vvv|-q]
+            [--radix bin|oct|hex]
+    aoc bench [--day N] [--part N] [--iterations N] [--save PATH] [--baseline PATH]
+    aoc sonar [--input PATH] [--window N]... [--every N] [--smooth avg:N|median:N|ema:A]
+              [--outliers K] [--outlier-window N]
+    aoc dive [--part N] [--input PATH] [--csv PATH] [--svg PATH] [--deeper-than N]
+             [--numbers i32|i64|i128|big]
+    aoc dive lint [--part N] [--input PATH] [--numbers i32|i64|i128|big]
+    aoc bingo generate [--seed N] [--boards N] [--size RxC] [--min N] [--max N] [--calls N]
+                       [--duplicates] [--malformed KIND]
+    aoc list
+
+Without --day, every day is run. --input defaults to the day's input.txt, use - for stdin.
+--format json prints one object per run, with the answer, named values and timings.
+-v prints a summary of how each answer was found, -vv progress, -vvv everything.
+--radix reads day 3's report as octal or hex digits rather than bits, with --day 3 only.
+bench times parse and solve separately over --iterations runs (default 10) of input.txt.
+--save writes the timings as json, --baseline compares against a file saved earlier.
+sonar counts depth increases as they arrive, from stdin unless --input is given, for
+windows of 1 and 3 unless --window is given. Counts are printed every 1000 depths (--every).
+--smooth prints every depth through a moving average, median or exponential filter.
+--outliers prints depths more than K standard deviations from the mean of the 10 before.
+dive follows a course file (part 2's rules unless --part 1) and reports the deepest point.
+--csv and --svg save every step of the course, --deeper-than finds the first step below N.
+Positions are i64 unless --numbers says otherwise, big never overflows.
+dive lint lists every bad or suspicious line in a course file, and fails if there are any.
+bingo generate prints a random game, the same one for the same --seed. By default it's 100
+5x5 boards with every number from 0 to 99 called once. Ranges of more than 2^20 numbers
+need --calls. --duplicates lets numbers repeat on a board and in the calls. --malformed
+breaks the game with one of short-row, long-row, missing-row, extra-row, not-a-number or
+no-blank-line.";
+
+struct RunArgs {
+    day: Option<u8>,
+    part: Option<u8>,
+    input: Option<String>,
+    format: Format,
+    log_level: Level,
+    radix: Option<Radix>,
+}
+
+impl Default for RunArgs {
+    fn default() -> Self {
+        RunArgs {
+            day: None,
+            part: None,
+            input: None,
+            format: Format::Text,
+            log_level: Level::Quiet,
+            radix: None,
+        }
+    }
+}
+
+struct BenchArgs {
+    day: Option<u8>,
+    part: Option<u8>,
+    iterations: usize,
+    save: Option<String>,
+    baseline: Option<String>,
+}
+
+impl Default for BenchArgs {
+    fn default() -> Self {
+        BenchArgs {
+            day: None,
+            part: None,
+            iterations: 10,
+            save: None,
+            baseline: None,
+        }
+    }
+}
+
+struct SonarArgs {
+    input: Option<String>,
+    windows: Vec<usize>,
+    every: usize,
+    smooth: Option<Box<dyn Filter>>,
+    outliers: Option<f64>,
+    outlier_window: usize,
+}
+
+struct DiveArgs {
+    lint: bool,
+    part: u8,
+    input: Option<String>,
+    csv: Option<String>,
+    svg: Option<String>,
+    deeper_than: Option<String>,
+    numbers: String,
+}
+
+struct BingoArgs {
+    generator: Generator,
+    malformed: Option<Defect>,
+}
+
+enum Command {
+    Run(RunArgs),
+    Bench(BenchArgs),
+    Sonar(SonarArgs),
+    Dive(DiveArgs),
+    Bingo(BingoArgs),
+    List,
+}
+
+fn parse_number<T: FromStr>(flag: &str, value: Option<String>) -> Result<T, String> {
+    let value = value.ok_or_else(|| format!("{} needs a value", flag))?;
+    value
+        .parse()
+        .map_err(|_| format!("{} expects a number, got '{}'", flag, value))
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    match args.next().as_deref() {
+        Some("run") => {
+            let mut run = RunArgs::default();
+            while let Some(arg) = args.next() {
+                match arg.as_str() {
+                    "--day" | "-d" => run.day = Some(parse_number(&arg, args.next())?),
+                    "--part" | "-p" => run.part = Some(parse_number(&arg, args.next())?),
+                    "--input" | "-i" => {
+                        run.input = Some(args.next().ok_or("--input needs a path")?);
+                    }
+                    "--format" | "-f" => {
+                        let name = args.next().ok_or("--format needs text or json")?;
+                        run.format = Format::from_name(&name)
+                            .ok_or_else(|| format!("Unknown format '{}'", name))?;
+                    }
+                    "--radix" => {
+                        let name = args.next().ok_or("--radix needs bin, oct or hex")?;
+                        run.radix = Some(
+                            Radix::from_name(&name)
+                                .ok_or_else(|| format!("Unknown radix '{}'", name))?,
+                        );
+                    }
+                    "--quiet" | "-q" => run.log_level = Level::Quiet,
+                    "--verbose" => run.log_level = log::from_verbosity(1),
+                    // -v, -vv, -vvv
+                    flag if flag.starts_with("-v") && flag[1..].chars().all(|c| c == 'v') => {
+                        run.log_level = log::from_verbosity(flag.len() - 1);
+                    }
+                    _ => return Err(format!("Unknown argument '{}'", arg)),
+                }
+            }
+            if run.input.is_some() && run.day.is_none() {
+                return Err("--input only makes sense with --day".to_string());
+            }
+            if run.radix.is_some() && run.day != Some(3) {
+                return Err("--radix only makes sense with --day 3".to_string());
+            }
+            Ok(Command::Run(run))
+        }
+        Some("bench") => {
+            let mut bench = BenchArgs::default();
+            while let Some(arg) = args.next() {
+                match arg.as_str() {
+                    "--day" | "-d" => bench.day = Some(parse_number(&arg, args.next())?),
+                    "--part" | "-p" => bench.part = Some(parse_number(&arg, args.next())?),
+                    "--iterations" | "-n" => bench.iterations = parse_number(&arg, args.next())?,
+                    "--save" => bench.save = Some(args.next().ok_or("--save needs a path")?),
+                    "--baseline" => {
+                        bench.baseline = Some(args.next().ok_or("--baseline needs a path")?);
+                    }
+                    _ => return Err(format!("Unknown argument '{}'", arg)),
+                }
+            }
+            if bench.iterations == 0 {
+                return Err("--iterations must be at least 1".to_string());
+            }
+            Ok(Command::Bench(bench))
+        }
+        Some("sonar") => {
+            let mut sonar = SonarArgs {
+                input: None,
+                windows: Vec::new(),
+                every: 1000,
+                smooth: None,
+                outliers: None,
+                outlier_window: 10,
+            };
+            while let Some(arg) = args.next() {
+                match arg.as_str() {
+                    "--input" | "-i" => {
+                        sonar.input = Some(args.next().ok_or("--input needs a path")?);
+                    }
+                    "--window" | "-w" => sonar.windows.push(parse_number(&arg, args.next())?),
+                    "--every" | "-e" => sonar.every = parse_number(&arg, args.next())?,
+                    "--smooth" => {
+                        let spec = args.next().ok_or("--smooth needs a filter, eg avg:5")?;
+                        sonar.smooth = Some(
+                            filter::parse_filter(&spec)
+                                .map_err(|e| format!("Bad filter for --smooth\n{}", e))?,
+                        );
+                    }
+                    "--outliers" => sonar.outliers = Some(parse_number(&arg, args.next())?),
+                    "--outlier-window" => {
+                        sonar.outlier_window = parse_number(&arg, args.next())?;
+                    }
+                    _ => return Err(format!("Unknown argument '{}'", arg)),
+                }
+            }
+            if sonar.windows.contains(&0) || sonar.outlier_window == 0 {
+                return Err("Window sizes must be at least 1".to_string());
+            }
+            if sonar.windows.is_empty() {
+                sonar.windows = vec![1, 3];
+            }
+            Ok(Command::Sonar(sonar))
+        }
+        Some("dive") => {
+            let mut dive = DiveArgs {
+                lint: false,
+                part: 2,
+                input: None,
+                csv: None,
+                svg: None,
+                deeper_than: None,
+                numbers: "i64".to_string(),
+            };
+            let mut args = args.peekable();
+            if args.peek().map(String::as_str) == Some("lint") {
+                args.next();
+                dive.lint = true;
+            }
+            while let Some(arg) = args.next() {
+                match arg.as_str() {
+                    "--part" | "-p" => dive.part = parse_number(&arg, args.next())?,
+                    "--input" | "-i" => {
+                        dive.input = Some(args.next().ok_or("--input needs a path")?);
+                    }
+                    "--csv" => dive.csv = Some(args.next().ok_or("--csv needs a path")?),
+                    "--svg" => dive.svg = Some(args.next().ok_or("--svg needs a path")?),
+                    "--deeper-than" => {
+                        dive.deeper_than = Some(args.next().ok_or("--deeper-than needs a depth")?);
+                    }
+                    "--numbers" => dive.numbers = args.next().ok_or("--numbers needs a type")?,
+                    _ => return Err(format!("Unknown argument '{}'", arg)),
+                }
+            }
+            if dive.part != 1 && dive.part != 2 {
+                return Err("--part must be 1 or 2".to_string());
+            }
+            if !["i32", "i64", "i128", "big"].contains(&dive.numbers.as_str()) {
+                return Err(format!("Unknown --numbers '{}'", dive.numbers));
+            }
+            if dive.lint && (dive.csv.is_some() || dive.svg.is_some() || dive.deeper_than.is_some())
+            {
+                return Err("dive lint only takes --part, --input and --numbers".to_string());
+            }
+            Ok(Command::Dive(dive))
+        }
+        Some("bingo") => {
+            if args.next().as_deref() != Some("generate") {
+                return Err("bingo needs a subcommand: generate".to_string());
+            }
+            let mut bingo = BingoArgs {
+                generator: Generator::default(),
+                malformed: None,
+            };
+            let (mut min, mut max) = (0, 99);
+            while let Some(arg) = args.next() {
+                let generator = &mut bingo.generator;
+                match arg.as_str() {
+                    "--seed" => generator.seed = parse_number(&arg, args.next())?,
+                    "--boards" => generator.boards = parse_number(&arg, args.next())?,
+                    "--size" => {
+                        let size = args.next().ok_or("--size needs rows and columns, eg 5x5")?;
+                        let (rows, cols) = size
+                            .split_once('x')
+                            .ok_or_else(|| format!("--size expects RxC, got '{}'", size))?;
+                        generator.rows = parse_number(&arg, Some(rows.to_string()))?;
+                        generator.cols = parse_number(&arg, Some(cols.to_string()))?;
+                    }
+                    "--min" => min = parse_number(&arg, args.next())?,
+                    "--max" => max = parse_number(&arg, args.next())?,
+                    "--calls" => generator.calls = Some(parse_number(&arg, args.next())?),
+                    "--duplicates" => generator.duplicates = Duplicates::Allowed,
+                    "--malformed" => {
+                        let name = args.next().ok_or("--malformed needs a kind of defect")?;
+                        bingo.malformed = Some(
+                            Defect::from_name(&name)
+                                .ok_or_else(|| format!("Unknown defect '{}'", name))?,
+                        );
+                    }
+                    _ => return Err(format!("Unknown argument '{}'", arg)),
+                }
+            }
+            if min > max {
+                return Err("--min can't be more than --max".to_string());
+            }
+            bingo.generator.numbers = min..=max;
+            Ok(Command::Bingo(bingo))
+        }
+        Some("list") => Ok(Command::List),
+        Some(other) => Err(format!("Unknown command '{}'", other)),
+        None => Err("Missing command".to_string()),
+    }
+}
+
+// Where the input comes from, for reporting
+fn input_name(entry: &Entry, input: &Option<String>) -> String {
+    match input.as_deref() {
+        Some(path) => path.to_string(),
+        None => entry.input_path("input.txt").display().to_string(),
+    }
+}
+
+fn read_input(entry: &Entry, input: &Option<String>) -> io::Result<String> {
+    match input.as_deref() {
+        Some("-") => {
+            let mut s = String::new();
+            io::stdin().read_to_string(&mut s)?;
+            Ok(s)
+        }
+        Some(path) => fs::read_to_string(path),
+        None => fs::read_to_string(entry.input_path("input.txt")),
+    }
+}
+
+// Solvers matching --day and --part
+fn select(day: Option<u8>, part: Option<u8>) -> Result<Vec<Entry>, String> {
+    let entries: Vec<Entry> = registry::all()
+        .into_iter()
+        .filter(|e| day.is_none_or(|day| e.day == day))
+        .filter(|e| part.is_none_or(|part| e.part == part))
+        .collect();
+    if entries.is_empty() {
+        return Err("No solver for that day / part".to_string());
+    }
+    Ok(entries)
+}
+
+fn run(args: RunArgs) -> Result<(), String> {
+    log::set_level(args.log_level);
+    let mut entries = select(args.day, args.part)?;
+    if let Some(radix) = args.radix {
+        for entry in &mut entries {
+            entry.solver = registry::diagnostics(entry.part, radix);
+        }
+    }
+
+    let mut failed = false;
+    for entry in entries {
+        let result = read_input(&entry, &args.input)
+            .map_err(Error::from)
+            .and_then(|input| entry.solver.run(&input));
+        let name = input_name(&entry, &args.input);
+        match (result, args.format) {
+            (Ok(run), Format::Text) => println!("{}", report::text_run(&entry, &run)),
+            (Ok(run), Format::Json) => println!("{}", report::json_run(&entry, &name, &run)),
+            (Err(e), Format::Text) => {
+                eprintln!("day {:02} part {}: error: {}", entry.day, entry.part, e);
+                failed = true;
+            }
+            (Err(e), Format::Json) => {
+                println!("{}", report::json_error(&entry, &name, &e));
+                failed = true;
+            }
+        }
+    }
+    if failed {
+        return Err("Some solvers failed".to_string());
+    }
+    Ok(())
+}
+
+fn load_baseline(path: &str) -> Result<Vec<Measurement>, String> {
+    let text = fs::read_to_string(path).map_err(|e| format!("Couldn't read {}: {}", path, e))?;
+    let value = serde_json::from_str(&text).map_err(|e| format!("{} isn't json: {}", path, e))?;
+    bench::from_json(&value).map_err(|e| format!("{} isn't a baseline: {}", path, e))
+}
+
+// One row of the bench table, with the change from the baseline if there is one
+fn print_stats(m: &Measurement, phase: &str, stats: &Stats, baseline: Option<&Stats>) {
+    let change = match baseline.and_then(|baseline| bench::change(baseline, stats)) {
+        Some(change) => format!("{:>14}", format!("{:+.1}%", change)),
+        None => String::new(),
+    };
+    println!(
+        "day {:02} part {} {:<5} {:>12.3} {:>12.3} {:>12.3}{}",
+        m.day, m.part, phase, stats.mean, stats.median, stats.stddev, change
+    );
+}
+
+fn bench(args: BenchArgs) -> Result<(), String> {
+    let entries = select(args.day, args.part)?;
+    let baseline = match &args.baseline {
+        Some(path) => load_baseline(path)?,
+        None => Vec::new(),
+    };
+
+    println!(
+        "{:<19}{:>13}{:>13}{:>13}{}",
+        "",
+        "mean ms",
+        "median ms",
+        "stddev ms",
+        if args.baseline.is_some() {
+            "   vs baseline"
+        } else {
+            ""
+        }
+    );
+    let mut measurements = Vec::new();
+    let mut failed = false;
+    for entry in entries {
+        let result = read_input(&entry, &None)
+            .map_err(Error::from)
+            .and_then(|input| bench::measure(&entry, &input, args.iterations));
+        match result {
+            Ok(m) => {
+                let before = baseline.iter().find(|b| b.day == m.day && b.part == m.part);
+                print_stats(&m, "parse", &m.parse, before.map(|b| &b.parse));
+                print_stats(&m, "solve", &m.solve, before.map(|b| &b.solve));
+                measurements.push(m);
+            }
+            Err(e) => {
+                eprintln!("day {:02} part {}: error: {}", entry.day, entry.part, e);
+                failed = true;
+            }
+        }
+    }
+
+    if let Some(path) = &args.save {
+        let json = serde_json::to_string_pretty(&bench::to_json(&measurements))
+            .map_err(|e| e.to_string())?;
+        fs::write(path, json + "\n").map_err(|e| format!("Couldn't write {}: {}", path, e))?;
+    }
+    if failed {
+        return Err("Some solvers failed".to_string());
+    }
+    Ok(())
+}
+
+fn sonar(mut args: SonarArgs) -> Result<(), String> {
+    let reader: Box<dyn BufRead> = match args.input.as_deref() {
+        None | Some("-") => Box::new(io::stdin().lock()),
+        Some(path) => Box::new(io::BufReader::new(
+            fs::File::open(path).map_err(|e| format!("Couldn't read {}: {}", path, e))?,
+        )),
+    };
+    let mut stream = Stream::new(&args.windows);
+    let mut outliers = args.outliers.map(|k| Outliers::new(args.outlier_window, k));
+
+    let result = stream
+        .read(
+            reader,
+            args.every,
+            |line, depth| {
+                if let Some(smoothed) = args.smooth.as_mut().and_then(|f| f.push(depth as f64)) {
+                    println!("line {}: {} -> {:.2}", line, depth, smoothed);
+                }
+                if let Some(anomaly) = outliers.as_mut().and_then(|o| o.push(line, depth)) {
+                    println!("{}", anomaly);
+                }
+            },
+            |stream| println!("{}", stream),
+        )
+        .map_err(|e| e.to_string());
+    // Whatever was read before an error still counts, unless it was just printed
+    let reported =
+        args.every > 0 && stream.depths() > 0 && stream.depths().is_multiple_of(args.every);
+    if !reported {
+        println!("{}", stream);
+    }
+    result
+}
+
+fn dive(args: DiveArgs) -> Result<(), String> {
+    let entry = &select(Some(2), Some(args.part))?[0];
+    let input = read_input(entry, &args.input).map_err(|e| e.to_string())?;
+    match (args.numbers.as_str(), args.lint) {
+        ("i32", false) => follow_course::<i32>(&args, &input),
+        ("i128", false) => follow_course::<i128>(&args, &input),
+        ("big", false) => follow_course::<BigInt>(&args, &input),
+        (_, false) => follow_course::<i64>(&args, &input),
+        ("i32", true) => lint_course::<i32>(&args, &input),
+        ("i128", true) => lint_course::<i128>(&args, &input),
+        ("big", true) => lint_course::<BigInt>(&args, &input),
+        (_, true) => lint_course::<i64>(&args, &input),
+    }
+}
+
+fn submarine<N: Number>(part: u8) -> Submarine<N> {
+    match part {
+        1 => Submarine::new(Naive),
+        _ => Submarine::new(Aim),
+    }
+}
+
+fn lint_course<N: Number>(args: &DiveArgs, input: &str) -> Result<(), String> {
+    let findings = dive::lint(submarine::<N>(args.part), input);
+    for finding in &findings {
+        println!("{}", finding);
+    }
+    let errors = findings
+        .iter()
+        .filter(|f| f.severity == Severity::Error)
+        .count();
+    let warnings = findings.len() - errors;
+    if findings.is_empty() {
+        println!("No problems found");
+        return Ok(());
+    }
+    Err(format!("{} errors, {} warnings", errors, warnings))
+}
+
+fn follow_course<N: Number>(args: &DiveArgs, input: &str) -> Result<(), String> {
+    let mut submarine = submarine::<N>(args.part);
+    let commands = parse::numbered_lines(input.as_bytes(), |line| submarine.parse(line))
+        .map_err(|e| e.to_string())?;
+    let trajectory = Trajectory::record(&mut submarine, &commands).map_err(|e| e.to_string())?;
+
+    let describe = |step: &Step<N>| match step.index {
+        0 => "the start".to_string(),
+        i => format!("step {}, line {} ({})", i, step.line, step.command),
+    };
+    let deepest = trajectory.max_depth();
+    println!("max depth {} at {}", deepest.state.depth, describe(deepest));
+    if let Some(threshold) = &args.deeper_than {
+        let threshold: N = threshold
+            .parse()
+            .map_err(|_| format!("--deeper-than expects a number, got '{}'", threshold))?;
+        match trajectory.first_deeper_than(&threshold) {
+            Some(step) => println!("first deeper than {} at {}", threshold, describe(step)),
+            None => println!("never deeper than {}", threshold),
+        }
+    }
+
+    if let Some(path) = &args.csv {
+        fs::write(path, trajectory.to_csv())
+            .map_err(|e| format!("Couldn't write {}: {}", path, e))?;
+    }
+    if let Some(path) = &args.svg {
+        fs::write(path, trajectory.to_svg())
+            .map_err(|e| format!("Couldn't write {}: {}", path, e))?;
+    }
+    Ok(())
+}
+
+fn bingo(args: BingoArgs) -> Result<(), String> {
+    match args.malformed {
+        Some(defect) => {
+            let malformed = args
+                .generator
+                .malformed(defect)
+                .map_err(|e| e.to_string())?;
+            print!("{}", malformed.input);
+            eprintln!("the defect is on line {}", malformed.line);
+        }
+        None => print!("{}", args.generator.game().map_err(|e| e.to_string())?),
+    }
+    Ok(())
+}
+
+fn main() {
+    let command = match parse_args(env::args().skip(1)) {
+        Ok(command) => command,
+        Err(e) => {
+            eprintln!("{}\n\n{}", e, USAGE);
+            process::exit(2);
+        }
+    };
+    let result = match command {
+        Command::Run(args) => run(args),
+        Command::Bench(args) => bench(args),
+        Command::Sonar(args) => sonar(args),
+        Command::Dive(args) => dive(args),
+        Command::Bingo(args) => bingo(args),
+        Command::List => {
+            for entry in registry::all() {
+                println!("day {:02} part {}  {}", entry.day, entry.part, entry.dir);
+            }
+            Ok(())
+        }
+    };
+    if let Err(e) = result {
+        eprintln!("{}", e);
+        process::exit(1);
+    }
+}
@@ -0,0 +1,77 @@
+use aoc_core::Puzzle;
+use binary_diagnostics::Radix;
+use std::path::PathBuf;
+
+// A solver, and where to find the inputs for it
+pub struct Entry {
+    pub day: u8,
+    pub part: u8,
+    // Directory the inputs live in, relative to the workspace root
+    pub dir: &'static str,
+    pub solver: Box<dyn Puzzle>,
+}
+
+impl Entry {
+    // input.txt, input_simple.txt etc
+    pub fn input_path(&self, file_name: &str) -> PathBuf {
+        workspace_root().join(self.dir).join(file_name)
+    }
+}
+
+pub fn workspace_root() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .unwrap()
+        .to_path_buf()
+}
+
+fn entry(day: u8, part: u8, dir: &'static str, solver: impl Puzzle + 'static) -> Entry {
+    Entry {
+        day,
+        part,
+        dir,
+        solver: Box::new(solver),
+    }
+}
+
+// Every solver, in day / part order
+pub fn all() -> Vec<Entry> {
+    vec![
+        entry(1, 1, "01-sonar-sweep", sonar_sweep::Part1),
+        entry(1, 2, "01-sonar-sweep", sonar_sweep::Part2),
+        entry(2, 1, "02-dive", dive::Part1),
+        entry(2, 2, "02-dive", dive::Part2),
+        entry(
+            3,
+            1,
+            "03-binary-diagnostics",
+            binary_diagnostics::Part1::default(),
+        ),
+        entry(
+            3,
+            2,
+            "03-binary-diagnostics",
+            binary_diagnostics::Part2::default(),
+        ),
+        entry(4, 1, "04-bingo", bingo::Part1),
+        entry(4, 2, "04-bingo", bingo::Part2),
+        entry(5, 1, "05-vents", vents::Part1),
+        entry(5, 2, "05-vents", vents::Part2),
+        entry(6, 1, "06-lanternfish", lanternfish::Part1),
+        entry(6, 2, "06-lanternfish", lanternfish::Part2),
+        entry(7, 1, "07-crabs", crabs::Part1),
+        entry(7, 2, "07-crabs", crabs::Part2),
+        entry(8, 1, "08-seven-segment-search", seven_segment_search::Part1),
+        entry(8, 2, "08-seven-segment-search", seven_segment_search::Part2),
+        entry(9, 1, "09-smoke-basin", smoke::Part1),
+        entry(9, 2, "09-smoke-basin", smoke::Part2),
+    ]
+}
+
+// Day 3's solver for reports written in another radix
+pub fn diagnostics(part: u8, radix: Radix) -> Box<dyn Puzzle> {
+    match part {
+        1 => Box::new(binary_diagnostics::Part1 { radix }),
+        _ => Box::new(binary_diagnostics::Part2 { radix }),
+    }
+}
@@ -0,0 +1,73 @@
+// How results are printed. Text is for people, json is one object per line for scripts.
+use crate::registry::Entry;
+use aoc_core::{Error, Run};
+use serde_json::{json, Map, Value};
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+}
+
+impl Format {
+    pub fn from_name(name: &str) -> Option<Format> {
+        match name {
+            "text" => Some(Format::Text),
+            "json" => Some(Format::Json),
+            _ => None,
+        }
+    }
+}
+
+// Numbers stay numbers. Integers past 64 bits don't fit a json number, so they're strings.
+fn json_value(value: &aoc_core::Value) -> Value {
+    match value {
+        aoc_core::Value::Int(n) => match (i64::try_from(*n), u64::try_from(*n)) {
+            (Ok(n), _) => json!(n),
+            (_, Ok(n)) => json!(n),
+            _ => json!(n.to_string()),
+        },
+        aoc_core::Value::Float(n) => json!(n),
+        aoc_core::Value::Bool(b) => json!(b),
+        aoc_core::Value::Text(s) => json!(s),
+    }
+}
+
+fn millis(d: Duration) -> f64 {
+    d.as_secs_f64() * 1000.0
+}
+
+pub fn json_run(entry: &Entry, input: &str, run: &Run) -> Value {
+    let values: Map<String, Value> = run
+        .answer
+        .details
+        .iter()
+        .map(|(name, value)| (name.to_string(), json_value(value)))
+        .collect();
+    json!({
+        "day": entry.day,
+        "part": entry.part,
+        "input": input,
+        "answer": json_value(&run.answer.value),
+        "values": values,
+        "timing": {
+            "parse_ms": millis(run.parse_time),
+            "solve_ms": millis(run.solve_time),
+            "total_ms": millis(run.parse_time + run.solve_time),
+        },
+    })
+}
+
+pub fn json_error(entry: &Entry, input: &str, error: &Error) -> Value {
+    json!({
+        "day": entry.day,
+        "part": entry.part,
+        "input": input,
+        "error": error.to_string(),
+    })
+}
+
+pub fn text_run(entry: &Entry, run: &Run) -> String {
+    format!("day {:02} part {}: {}", entry.day, entry.part, run.answer)
+}
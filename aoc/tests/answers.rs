@@ -0,0 +1,80 @@
+// Runs every registered solver against the inputs listed in answers.toml, and fails if any
+// answer has changed.
+use aoc::registry::{self, Entry};
+use std::fs;
+use toml::{Table, Value};
+
+fn manifest() -> Table {
+    let path = registry::workspace_root().join("answers.toml");
+    let text = fs::read_to_string(&path).expect("Couldn't read answers.toml");
+    text.parse().expect("answers.toml isn't valid toml")
+}
+
+// [dayNN.partN] table for this entry
+fn answers_for<'a>(manifest: &'a Table, entry: &Entry) -> Option<&'a Table> {
+    manifest
+        .get(&format!("day{:02}", entry.day))?
+        .get(format!("part{}", entry.part))?
+        .as_table()
+}
+
+fn expected_string(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+#[test]
+fn answers_match_manifest() {
+    let manifest = manifest();
+    let mut failures = Vec::new();
+    let mut checked = 0;
+
+    for entry in registry::all() {
+        let name = format!("day {:02} part {}", entry.day, entry.part);
+        let answers = match answers_for(&manifest, &entry) {
+            Some(answers) => answers,
+            None => {
+                failures.push(format!("{}: no answers in answers.toml", name));
+                continue;
+            }
+        };
+        for (file, expected) in answers {
+            let path = entry.input_path(&format!("{}.txt", file));
+            let input = match fs::read_to_string(&path) {
+                Ok(input) => input,
+                Err(e) => {
+                    failures.push(format!("{} {}: {}", name, path.display(), e));
+                    continue;
+                }
+            };
+            let expected = expected_string(expected);
+            match entry.solver.run(&input) {
+                Ok(run) if run.answer.value.to_string() == expected => checked += 1,
+                Ok(run) => failures.push(format!(
+                    "{} {}.txt: expected {}, got {}",
+                    name, file, expected, run.answer
+                )),
+                Err(e) => failures.push(format!("{} {}.txt: error: {}", name, file, e)),
+            }
+        }
+    }
+
+    assert!(failures.is_empty(), "\n{}\n", failures.join("\n"));
+    assert!(checked > 0);
+}
+
+#[test]
+fn every_solver_has_a_full_input_answer() {
+    let manifest = manifest();
+    for entry in registry::all() {
+        let answers = answers_for(&manifest, &entry);
+        assert!(
+            answers.is_some_and(|answers| answers.contains_key("input")),
+            "day {:02} part {} has no answer for input.txt in answers.toml",
+            entry.day,
+            entry.part
+        );
+    }
+}
@@ -0,0 +1,47 @@
+use aoc::bench::{self, Measurement, Stats};
+use std::time::Duration;
+
+fn ms(values: &[u64]) -> Vec<Duration> {
+    values.iter().map(|&v| Duration::from_millis(v)).collect()
+}
+
+#[test]
+fn stats_of_samples() {
+    let stats = Stats::from_samples(&ms(&[4, 2, 8, 6]));
+    assert_eq!(stats.mean, 5.0);
+    assert_eq!(stats.median, 5.0);
+    assert_eq!(stats.stddev, 5.0f64.sqrt());
+
+    let stats = Stats::from_samples(&ms(&[1, 100, 3]));
+    assert_eq!(stats.median, 3.0);
+}
+
+#[test]
+fn baseline_round_trips_through_json() {
+    let stats = Stats::from_samples(&ms(&[1, 2, 3]));
+    let measurements = vec![Measurement {
+        day: 7,
+        part: 2,
+        iterations: 3,
+        parse: stats,
+        solve: stats,
+    }];
+    let text = bench::to_json(&measurements).to_string();
+    let loaded = bench::from_json(&serde_json::from_str(&text).unwrap());
+    assert_eq!(loaded, Ok(measurements));
+}
+
+#[test]
+fn baselines_must_be_measurements() {
+    let e = bench::from_json(&serde_json::json!({"foo": 1})).unwrap_err();
+    assert_eq!(e, "expected a list of measurements");
+    let e = bench::from_json(&serde_json::json!([{"day": 1}])).unwrap_err();
+    assert_eq!(e, "entry 1 isn't a measurement: {\"day\":1}");
+}
+
+#[test]
+fn change_from_baseline() {
+    let before = Stats::from_samples(&ms(&[10]));
+    let after = Stats::from_samples(&ms(&[5]));
+    assert_eq!(bench::change(&before, &after), Some(-50.0));
+}
@@ -0,0 +1,24 @@
+use aoc::{registry, report};
+use aoc_core::{Answer, Run};
+use std::time::Duration;
+
+#[test]
+fn json_keeps_numbers_and_flags_typed() {
+    let entry = &registry::all()[0];
+    let run = Run {
+        answer: Answer::new(1681)
+            .with("sparse", true)
+            .with("ratio", 0.5)
+            .with("run", "3 up")
+            .with("huge", u128::MAX),
+        parse_time: Duration::from_millis(1),
+        solve_time: Duration::from_millis(2),
+    };
+    let json = report::json_run(entry, "input.txt", &run);
+    assert_eq!(json["answer"], 1681);
+    assert_eq!(json["values"]["sparse"], true);
+    assert_eq!(json["values"]["ratio"], 0.5);
+    assert_eq!(json["values"]["run"], "3 up");
+    assert_eq!(json["values"]["huge"], u128::MAX.to_string());
+    assert_eq!(report::text_run(entry, &run), "day 01 part 1: 1681");
+}